@@ -0,0 +1,20 @@
+//! Detects rustc features used by egui_struct, that are newer than its MSRV
+
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(egui_struct_diagnostic)");
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let minor = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| {
+            let version = String::from_utf8(output.stdout).ok()?;
+            version.split(['.', ' ']).nth(2)?.parse::<u32>().ok()
+        });
+    //`#[diagnostic::on_unimplemented]` is stable since 1.78
+    if minor.is_some_and(|minor| minor >= 78) {
+        println!("cargo:rustc-cfg=egui_struct_diagnostic");
+    }
+}
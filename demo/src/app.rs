@@ -72,7 +72,12 @@ lazy_static::lazy_static! {
     );
 }
 #[derive(EguiStruct)]
-#[eguis(rename_all = "Sentence", resetable = "struct_default")]
+#[eguis(
+    rename_all = "Sentence",
+    resetable = "struct_default",
+    group(name = "Text"),
+    group(name = "Wide integers", start_collapsed = true)
+)]
 pub struct Data {
     #[eguis(skip)]
     skipped_data: u32,
@@ -88,6 +93,18 @@ pub struct Data {
     #[eguis(resetable = "not_resetable")]
     not_resetable_string: String,
 
+    #[eguis(config = ConfigStr::Password, group = "Text")]
+    password: String,
+
+    #[eguis(
+        group = "Text",
+        config = ConfigStr::TextEdit(ConfigStrEdit::new(StrEditMode::SingleLine).hint_text("letters only").char_limit(16).validator(|s| if s.chars().all(char::is_alphabetic) {Ok(())} else {Err("Only letters are allowed".to_string())}))
+    )]
+    validated_string: String,
+
+    #[eguis(config = ConfigStr::Code, group = "Text")]
+    code: String,
+
     #[eguis(resetable = "field_default")]
     i8: i8,
 
@@ -95,7 +112,9 @@ pub struct Data {
     i16: i16,
 
     i32: i32,
+    #[eguis(group = "Wide integers")]
     i64: i64,
+    #[eguis(group = "Wide integers")]
     i128: i128,
     isize: isize,
 
@@ -104,14 +123,26 @@ pub struct Data {
 
     #[eguis(
         hint = "This is also isize but limited to range <5,11>",
-        config = "Slider(5,11)"
+        config = Slider(5,11)
     )]
     limited_isize: isize,
 
-    #[eguis(config = "SliderStep(5,110,5)")]
+    #[eguis(config = SliderStep(5,110,5))]
     stepped_isize: isize,
 
+    /// Drawn by custom widget function
+    #[eguis(widget = percent_slider)]
+    opacity: f32,
+
     bool: bool,
+
+    use_proxy: bool,
+    /// Shown only if `use_proxy` is set
+    #[eguis(visible_if = self.use_proxy, validate = validate_port)]
+    proxy_port: u16,
+    /// Editable only if `use_proxy` is set
+    #[eguis(enabled_if = self.use_proxy)]
+    proxy_host: String,
     u8: u8,
     u16: u16,
 
@@ -141,25 +172,56 @@ pub struct Data {
     #[eguis(config = "Some(&mut [2,3,5,7,11,13,17,19].into_iter())")]
     u8_combobox_wrapper: Combobox<u8>,
 
-    #[eguis(config = "ComboBox(&mut [2,3,5,7,11,13,17,19].into_iter())")]
+    #[eguis(config = ComboBox(&mut [2,3,5,7,11,13,17,19].into_iter()))]
     u8_combobox_config: u8,
 
+    #[eguis(
+        hint = "Long lists in combobox have search field",
+        config = ComboBox(&mut (0..=1000))
+    )]
+    u16_combobox_large: u16,
+
     //this(cloning) is not elegant but for most cases would work well enough
     #[eguis(config = "Some(&mut STATIC_COMBOBOX.read().clone().into_iter())")]
     static_combobox: Combobox<String>,
 
+    #[eguis(config = "Some(&mut [2,3,5,7,11,13,17,19].into_iter())")]
+    u8_multi_combobox: MultiCombobox<Vec<u8>>,
+
+    #[eguis(
+        hint = "Accepts any text, but suggests values from the list",
+        config = ConfigStr::Autocomplete(&mut STATIC_COMBOBOX.read().clone().into_iter())
+    )]
+    autocomplete: String,
+
     nested_struct: SubData,
+    #[eguis(flatten)]
+    common: CommonSettings,
     unnamed_struct: TupleStruct,
     primary_color: Color,
     secondary_color: Color,
 
-    #[eguis(hint = "This is Option<_>", start_collapsed = true)]
+    /// This is Option<_>
+    #[eguis(start_collapsed = true)]
     optional: Option<SubData>,
 
     #[eguis(hint = "This is also Option, but as inner value is simple it is presented inline")]
     optional_string: Option<String>,
 
     list: Vec<Color>,
+    generic_pair: Pair<f32>,
+}
+
+fn percent_slider(value: &mut f32, ui: &mut egui::Ui, _id: egui::Id) -> egui::Response {
+    ui.add(egui::Slider::new(value, 0.0..=100.0).suffix(" %"))
+}
+
+fn validate_port(port: &u16) -> Result<(), String> {
+    if *port < 1024 {
+        Err("Ports below 1024 are reserved".to_string())
+    } else {
+        Ok(())
+    }
 }
 
 impl Default for Data {
@@ -174,6 +236,9 @@ impl Default for Data {
             skipped_data: 0,
             string: "Hello!".to_string(),
             not_resetable_string: "Hello!".to_string(),
+            password: "secret".to_string(),
+            validated_string: String::new(),
+            code: "fn main() {\n\tprintln!(\"Hello!\");\n}".to_string(),
             i8: 42,
             i16: 1555,
             i32: -242522,
@@ -183,7 +248,11 @@ impl Default for Data {
             isize_imut: -333,
             limited_isize: 6,
             stepped_isize: 50,
+            opacity: 80.0,
             bool: true,
+            use_proxy: false,
+            proxy_port: 8080,
+            proxy_host: "localhost".to_string(),
             u8: 94,
             u16: 14029,
             u32: 3025844,
@@ -195,8 +264,15 @@ impl Default for Data {
             usize_boxed: Box::new(usize::MAX),
             u8_combobox_wrapper: Combobox(3),
             u8_combobox_config: 3,
+            u16_combobox_large: 500,
             static_combobox: Combobox("default name".to_string()),
+            u8_multi_combobox: MultiCombobox(vec![3, 7]),
+            autocomplete: "Olaf".to_string(),
             nested_struct: SubData::default(),
+            common: CommonSettings {
+                enabled: true,
+                label: "Fields of this struct are shown inline".to_string(),
+            },
             unnamed_struct: TupleStruct::default(),
             primary_color: Color::default(),
             secondary_color: Color::default(),
@@ -215,6 +291,10 @@ impl Default for Data {
                     },
                 },
             ],
+            generic_pair: Pair {
+                first: 0.5,
+                second: 1.5,
+            },
         }
     }
 }
@@ -239,23 +319,48 @@ pub struct Metadata {
 }
 
 #[derive(EguiStruct, Default)]
-#[eguis(resetable = "struct_default")]
+#[eguis(resetable = "struct_default", sort = "alphabetical")]
 pub struct SubData {
     value: String,
     number: u32,
 }
 
+/// Settings shared by many structs (shown inline with `#[eguis(flatten)]`)
+#[derive(EguiStruct, Default)]
+pub struct CommonSettings {
+    enabled: bool,
+    label: String,
+}
+
+/// Generic struct (trait bounds are inferred from field types)
+#[derive(EguiStruct, Default)]
+pub struct Pair<T> {
+    first: T,
+    second: T,
+}
+
 #[derive(Default)]
 pub struct DemoApp {
     data: Data,
+    last_changed: Vec<FieldPath>,
 }
 
 impl eframe::App for DemoApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        let Self { data } = self;
+        let Self { data, last_changed } = self;
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.style_mut().visuals.striped = true;
-            data.show_top(ui, RichText::new("Data").heading(), None);
+            for (path, error) in data.validate_all() {
+                ui.colored_label(ui.visuals().error_fg_color, format!("{}: {}", path, error));
+            }
+            if !last_changed.is_empty() {
+                let paths: Vec<String> = last_changed.iter().map(ToString::to_string).collect();
+                ui.label(format!("Last changed: {}", paths.join(", ")));
+            }
+            let (_, changed) = data.show_top_tracked(ui, RichText::new("Data").heading(), None);
+            if !changed.is_empty() {
+                *last_changed = changed;
+            }
         });
     }
 }
@@ -10,6 +10,11 @@ fn main() -> eframe::Result<()> {
     eframe::run_native(
         "egui_struct demo",
         native_options,
-        Box::new(|_creation_context| Ok(Box::<DemoApp>::default())),
+        Box::new(|creation_context| {
+            egui_struct::set_translator(&creation_context.egui_ctx, |key| {
+                rust_i18n::t!(key).to_string()
+            });
+            Ok(Box::<DemoApp>::default())
+        }),
     )
 }
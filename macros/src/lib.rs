@@ -2,8 +2,11 @@ use convert_case::{Case, Converter, Pattern};
 use darling::{ast, FromDeriveInput, FromVariant};
 use darling::{FromField, FromMeta};
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote, ToTokens};
-use syn::{DeriveInput, Expr, Index};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{parse_quote, Attribute, DeriveInput, Expr, Index, LitStr, Path, Token, WherePredicate};
 use syn::{Ident, Type};
 
 #[derive(Debug, Default, Clone, FromMeta, PartialEq)]
@@ -22,6 +25,15 @@ enum Resetable {
     ///INTERNAL USE ONLY! reset button will reset to value stored by oncelock named by contained ident
     WithStructExpr(Ident),
 }
+#[derive(Debug, Default, Clone, FromMeta, PartialEq)]
+enum Sort {
+    #[default]
+    ///Fields are shown in declaration order (or by `order` attribute)
+    Declaration,
+    ///Fields are sorted by displayed label
+    Alphabetical,
+}
+
 impl Resetable {
     fn mask(&self, mask: &Option<Self>) -> Self {
         if let Some(mask) = mask {
@@ -32,20 +44,59 @@ impl Resetable {
     }
 }
 
+///Config expression; accepts either plain expression (`config = Slider(0, 10)`) or string literal (`config = "Slider(0, 10)"`)
+#[derive(Debug, Clone)]
+struct ConfigExpr(Expr);
+impl FromMeta for ConfigExpr {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit),
+                ..
+            }) => lit.parse::<Expr>().map(ConfigExpr).map_err(|e| {
+                darling::Error::custom(format!("malformed config expression: {}", e)).with_span(lit)
+            }),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => Ok(ConfigExpr(expr.clone())),
+        }
+    }
+}
+
+///Where clause predicates passed as string (`bound = "T: EguiStruct, U: Default"`)
+#[derive(Debug, Clone)]
+struct Bound(Vec<WherePredicate>);
+impl FromMeta for Bound {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        if let syn::Lit::Str(lit) = value {
+            lit.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)
+                .map(|x| Bound(x.into_iter().collect()))
+                .map_err(|e| {
+                    darling::Error::custom(format!("malformed bound: {}", e)).with_span(lit)
+                })
+        } else {
+            Err(darling::Error::unexpected_lit_type(value))
+        }
+    }
+}
+
 #[derive(Debug, Clone, FromField)]
-#[darling(attributes(eguis, eguisM, eguisI))]
+#[darling(attributes(eguis, eguisM, eguisI), forward_attrs(doc, serde))]
 struct EField {
     ident: Option<Ident>,
     ty: Type,
+    attrs: Vec<Attribute>,
 
     /// Name of the field to be displayed on UI labels
     rename: Option<String>,
     /// Doesn't generate code for the given field
     #[darling(default)]
     skip: bool,
-    ///hint to be displayed on hover
+    ///hint to be displayed on hover (if not set, doc comment is used)
     #[darling(default)]
     hint: String,
+    ///do not use doc comment as hint
+    #[darling(default)]
+    no_doc_hint: bool,
     ///field is always imutable
     #[darling(default)]
     imut: bool,
@@ -56,9 +107,9 @@ struct EField {
     /// Use function callback (when value has been changed; signature: fn(&mut self) )
     on_change_struct: Option<Expr>,
     /// pass format/config object to customise how field is displayed
-    imconfig: Option<String>,
+    imconfig: Option<ConfigExpr>,
     /// pass format/config object to customise how field is displayed (when mutable)
-    config: Option<String>,
+    config: Option<ConfigExpr>,
     /// add reset(to default) button (what is called default depends on selected Resetable::*; overrides resetable setting for parrent struct)
     resetable: Option<Resetable>,
     /// Expression (closure surounded by `()` OR function path) called to map field to another type before displaying
@@ -83,21 +134,46 @@ struct EField {
     eclone: Option<Expr>,
     /// Override fields `start_collapsed()` output (if set true field will always start collapsed)
     start_collapsed: Option<bool>,
+    /// Function that draws primitive cell instead of `show_primitive` (signature: `fn(&mut field_type, &mut Ui, Id) -> Response`)
+    /// - label, hint, reset button, `on_change` & childs are still handled as usual
+    widget: Option<Expr>,
+    /// similar to `widget`, but used for EguiStructImut (signature: `fn(&field_type, &mut Ui, Id) -> Response`)
+    widget_imut: Option<Expr>,
+    /// Position of field (fields are sorted by it; equal values keep declaration order)
+    #[darling(default)]
+    order: i32,
+    /// Show fields of nested struct directly in parent rows (instead of as collapsible row)
+    #[darling(default)]
+    flatten: bool,
+    /// Show field inside collapsible group with this name (if struct declares groups, has to be one of them)
+    group: Option<LitStr>,
+    /// Expression (can use `self`) that controls if field is shown
+    visible_if: Option<Expr>,
+    /// Expression (can use `self`) that controls if field is enabled (disabled field is shown greyed out)
+    enabled_if: Option<Expr>,
+    /// Function that checks if field value is valid (signature: `fn(&field_type) -> Result<(), String>`)
+    validate: Option<Expr>,
+    /// Override where clause predicates inferred from this field type
+    bound: Option<Bound>,
 }
 #[derive(Debug, FromVariant)]
-#[darling(attributes(eguis, eguisM, eguisI))]
+#[darling(attributes(eguis, eguisM, eguisI), forward_attrs(doc, serde))]
 struct EVariant {
     ident: Ident,
     fields: ast::Fields<EField>,
+    attrs: Vec<Attribute>,
 
     /// Name of the field to be displayed on UI labels
     rename: Option<String>,
     /// Don't generate code for the given variant
     #[darling(default)]
     skip: bool,
-    ///hint to be displayed on hover
+    ///hint to be displayed on hover (if not set, doc comment is used)
     #[darling(default)]
     hint: String,
+    ///do not use doc comment as hint
+    #[darling(default)]
+    no_doc_hint: bool,
     ///variant is always imutable
     #[darling(default)]
     imut: bool,
@@ -105,19 +181,78 @@ struct EVariant {
     i18n: Option<String>,
     ///add reset(to default) button to all inner fields (overrides resetable enum-level setting)
     resetable: Option<Resetable>,
+    /// Expression (of type `Self`, has to create this variant) used when variant is selected in combobox (instead of calling `Default::default()` for each field)
+    default: Option<Expr>,
+    /// Function (signature: `fn() -> Self`) used when variant is selected in combobox (same as `default = path()`)
+    constructor: Option<Path>,
+}
+
+impl EVariant {
+    /// Code that creates new value of this variant (used when variant is selected in combobox)
+    fn new_value(&self, fields_default: TokenStream) -> TokenStream {
+        if let Some(expr) = &self.default {
+            quote! { #expr }
+        } else if let Some(path) = &self.constructor {
+            quote! { #path() }
+        } else {
+            fields_default
+        }
+    }
+
+    /// Code that sets `self` to new value of this variant (created with `new_value`) & then runs `then` with fields of new value bound by `pattern`
+    ///
+    /// If `default`/`constructor` creates another variant, `self` is left unchanged
+    fn set_new_value(
+        &self,
+        new_value: TokenStream,
+        pattern: TokenStream,
+        then: TokenStream,
+    ) -> TokenStream {
+        quote! {
+            let mut new_value: Self = #new_value;
+            let matched = if let #pattern = &mut new_value { #then true } else { false };
+            if matched {
+                *self = new_value;
+            }
+        }
+    }
+}
+
+/// Variant created by `default` expression, if it is written as plain variant constructor (`Self::A(..)`/`Type::A{..}`/`Self::A`)
+fn expr_variant<'a>(expr: &'a Expr, ty: &Ident) -> Option<&'a Ident> {
+    let path = match unparen(expr) {
+        Expr::Call(call) => match unparen(&call.func) {
+            Expr::Path(path) => &path.path,
+            _ => return None,
+        },
+        Expr::Struct(expr) => &expr.path,
+        Expr::Path(path) => &path.path,
+        _ => return None,
+    };
+    let mut segments = path.segments.iter().rev();
+    let variant = segments.next()?;
+    let owner = segments.next()?;
+    (owner.ident == "Self" || owner.ident == *ty).then_some(&variant.ident)
 }
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(eguis, eguisM, eguisI))]
+#[darling(attributes(eguis, eguisM, eguisI), forward_attrs(doc, serde))]
 struct EStruct {
     ident: Ident,
     generics: syn::Generics,
     data: ast::Data<EVariant, EField>,
+    attrs: Vec<Attribute>,
 
     ///rename all variant names to selected case
-    rename_all: Option<String>,
+    rename_all: Option<LitStr>,
     ///prefix to be added to i18n keys
     prefix: Option<String>,
+    ///hint to be displayed on hover of fields of this type, that does not have own hint (if not set, doc comment is used)
+    #[darling(default)]
+    hint: String,
+    ///do not use doc comments as hints (for struct/enum itself and all fields/variants)
+    #[darling(default)]
+    no_doc_hint: bool,
     ///do not generate EguiStruct impl
     #[darling(default)]
     no_mut: bool,
@@ -136,6 +271,182 @@ struct EStruct {
     /// Set `start_collapsed()` implementation (if not specified fn return `false`)
     #[darling(default)]
     start_collapsed: Option<Expr>,
+    /// Override where clause predicates inferred from field types
+    bound: Option<Bound>,
+    /// Groups of fields (order of declaration is order of groups)
+    #[darling(multiple, rename = "group")]
+    groups: Vec<EGroup>,
+    /// Function that checks if whole struct/enum is valid (signature: `fn(&Self) -> Result<(), String>`)
+    validate: Option<Expr>,
+    /// Order in which fields are shown
+    #[darling(default)]
+    sort: Sort,
+    /// Honour serde attributes (`rename`, `rename_all`, `rename_all_fields`, `skip`, `skip_serializing`, `flatten`); always enabled with `serde-attrs` feature
+    #[darling(default)]
+    serde: bool,
+    /// "Copy value"/"Paste value" copy whole struct/enum as RON text (requires `serde` feature of egui_struct & type implementing `Serialize`+`Deserialize`)
+    #[darling(default)]
+    clipboard: bool,
+}
+
+///Struct/enum level declaration of fields group
+#[derive(Debug, Clone, FromMeta)]
+struct EGroup {
+    name: String,
+    ///group is initially collapsed
+    #[darling(default)]
+    start_collapsed: bool,
+}
+
+/// Where clause predicates for each generated trait impl
+#[derive(Default)]
+struct Bounds {
+    imut: Vec<WherePredicate>,
+    mutable: Vec<WherePredicate>,
+    eclone: Vec<WherePredicate>,
+    eeq: Vec<WherePredicate>,
+}
+impl Bounds {
+    /// Infer bounds from types of fields that use generic type params (or use `bound` attribute)
+    fn infer(input: &EStruct) -> Self {
+        if let Some(Bound(bound)) = &input.bound {
+            return Bounds {
+                imut: bound.clone(),
+                mutable: bound.clone(),
+                eclone: bound.clone(),
+                eeq: bound.clone(),
+            };
+        }
+        let params: Vec<&Ident> = input.generics.type_params().map(|x| &x.ident).collect();
+        let mut bounds = Bounds::default();
+        if params.is_empty() {
+            return bounds;
+        }
+        let mut add_fields =
+            |fields: &ast::Fields<EField>, imut: bool, resetable: Resetable, need_default: bool| {
+                let single = fields.style == ast::Style::Tuple
+                    && fields.iter().filter(|f| !f.skip).count() == 1;
+                for field in fields.iter() {
+                    let ty = &field.ty;
+                    if !uses_type_param(ty.to_token_stream(), &params) {
+                        continue;
+                    }
+                    if let Some(Bound(bound)) = &field.bound {
+                        for pred in bound {
+                            bounds.push_all(pred.clone());
+                        }
+                        continue;
+                    }
+                    if need_default {
+                        //enum variant fields are set to default when variant is selected in combobox
+                        push(
+                            &mut bounds.mutable,
+                            parse_quote!(#ty: ::std::default::Default),
+                        );
+                        push(
+                            &mut bounds.eclone,
+                            parse_quote!(#ty: ::std::default::Default),
+                        );
+                    }
+                    if field.skip {
+                        continue;
+                    }
+                    let imut = imut || field.imut;
+                    if field.map_pre_ref.is_none() {
+                        push(
+                            &mut bounds.imut,
+                            parse_quote!(#ty: ::egui_struct::EguiStructImut),
+                        );
+                        if imut {
+                            push(
+                                &mut bounds.mutable,
+                                parse_quote!(#ty: ::egui_struct::EguiStructImut),
+                            );
+                        }
+                        if field.eeq.is_none() {
+                            push(
+                                &mut bounds.eeq,
+                                parse_quote!(#ty: ::egui_struct::EguiStructEq),
+                            );
+                        }
+                    }
+                    if !imut && field.map_pre.is_none() && field.map_pre_ref.is_none() {
+                        push(
+                            &mut bounds.mutable,
+                            parse_quote!(#ty: ::egui_struct::EguiStruct),
+                        );
+                        if resetable.mask(&field.resetable) == Resetable::FieldDefault {
+                            push(
+                                &mut bounds.mutable,
+                                parse_quote!(#ty: ::std::default::Default),
+                            );
+                        }
+                    }
+                    if single {
+                        //`SIMPLE` of single field tuple is taken from field type
+                        push(
+                            &mut bounds.imut,
+                            parse_quote!(#ty: ::egui_struct::EguiStructImut),
+                        );
+                        push(
+                            &mut bounds.mutable,
+                            parse_quote!(#ty: ::egui_struct::EguiStruct),
+                        );
+                    }
+                    if field.eclone.is_none() {
+                        push(
+                            &mut bounds.eclone,
+                            parse_quote!(#ty: ::egui_struct::EguiStructClone),
+                        );
+                    }
+                }
+            };
+        match &input.data {
+            ast::Data::Enum(variants) => {
+                for variant in variants.iter().filter(|v| !v.skip) {
+                    let need_default = variant.default.is_none() && variant.constructor.is_none();
+                    add_fields(
+                        &variant.fields,
+                        variant.imut,
+                        input.resetable.mask(&variant.resetable),
+                        need_default,
+                    );
+                }
+            }
+            ast::Data::Struct(fields) => add_fields(fields, false, input.resetable.clone(), false),
+        }
+        bounds
+    }
+    fn push_all(&mut self, pred: WherePredicate) {
+        push(&mut self.imut, pred.clone());
+        push(&mut self.mutable, pred.clone());
+        push(&mut self.eclone, pred.clone());
+        push(&mut self.eeq, pred);
+    }
+}
+fn push(preds: &mut Vec<WherePredicate>, pred: WherePredicate) {
+    if !preds.contains(&pred) {
+        preds.push(pred);
+    }
+}
+/// Check if type (tokens) contains any of generic type params
+fn uses_type_param(ty: TokenStream, params: &[&Ident]) -> bool {
+    ty.into_iter().any(|tt| match tt {
+        proc_macro2::TokenTree::Group(group) => uses_type_param(group.stream(), params),
+        proc_macro2::TokenTree::Ident(ident) => params.contains(&&ident),
+        _ => false,
+    })
+}
+/// Where clause of struct/enum extended with `preds`
+fn where_clause(generics: &syn::Generics, preds: &[WherePredicate]) -> TokenStream {
+    let mut generics = generics.clone();
+    if !preds.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(preds.iter().cloned());
+    }
+    generics.where_clause.to_token_stream()
 }
 
 fn handle_enum(
@@ -144,7 +455,8 @@ fn handle_enum(
     case: &Option<Converter>,
     input: &EStruct,
 ) -> TokenStream {
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let bounds = Bounds::infer(input);
     let ty = input.ident.clone();
     let mut simple: bool = true;
     let mut reset_to_struct_default = false;
@@ -159,6 +471,8 @@ fn handle_enum(
     let mut show_primitive_mut_arm = Vec::new();
     let mut eclone_arm = Vec::new();
     let mut eeq_arm = Vec::new();
+    let mut validate_arm = Vec::new();
+    let mut validate_inline_arm = Vec::new();
 
     let mut resetable = input.resetable.clone();
     let mut reset_to_struct_expr = Vec::new();
@@ -225,16 +539,17 @@ fn handle_enum(
                 let mut fields_names2 = Vec::new();
                 for (idx, field) in variant.fields.fields.iter().enumerate() {
                     let field_type = &field.ty;
-                    fields_default.push(quote! { #field_type::default(), });
+                    fields_default.push(quote_spanned! {field_type.span()=> <#field_type as ::egui_struct::VariantFieldDefault>::variant_field_default(), });
                     fields_names.push(format_ident!("_field_{}", idx));
                     fields_names2.push(format_ident!("_2_field_{}", idx));
                 }
                 let vident_w_inner = quote! { Self :: #vident(#(#fields_names),*)};
                 let vident_w_inner2 = quote! { Self :: #vident(#( #fields_names2),*)};
+                let variant_new = variant.new_value(quote! { Self:: #vident(#(#fields_default)*) });
                 let (
                     _reset_to_struct_default,
                     fields_code,
-                    mut fields_code_mut,
+                    fields_code_mut,
                     fields_map_eclone,
                     fields_map_eeq,
                     single_field,
@@ -251,8 +566,12 @@ fn handle_enum(
                     vresetable,
                     Some(vident_w_inner.clone()),
                 );
+                let vprefix = prefix.clone() + &vident.to_string() + ".";
+                let fields_code = group_fields(fields_code, &variant.fields, input, &vprefix, case);
+                let mut fields_code_mut =
+                    group_fields(fields_code_mut, &variant.fields, input, &vprefix, case);
                 reset_to_struct_default |= _reset_to_struct_default;
-                if fields_code.len() == 1 {
+                if variant.fields.iter().filter(|f| !f.skip).count() == 1 {
                     let fident = format_ident!("_field_{}", fidx);
                     let single_field = single_field.unwrap();
                     let fty = single_field.ty;
@@ -265,8 +584,8 @@ fn handle_enum(
 
                     let imconfig = get_config(single_field.imconfig);
                     let config = get_config(single_field.config);
-                    has_childs_arm.push(quote! { Self:: #vident(..) => ! #fty::SIMPLE_IMUT,});
-                    has_childs_mut_arm.push(quote! { Self:: #vident(..) => ! #fty::SIMPLE,});
+                    has_childs_arm.push(quote! { Self:: #vident(..) => ! <#fty as ::egui_struct::EguiStructImut>::SIMPLE_IMUT,});
+                    has_childs_mut_arm.push(quote! { Self:: #vident(..) => ! <#fty as ::egui_struct::EguiStruct>::SIMPLE,});
                     let primitive_imut = quote! {#vident_w_inner => response |= #map_ref(#fident).show_primitive_imut(ui,#imconfig,id),};
                     let primitive_mut = quote! { #vident_w_inner => {let mut mapped=#map(#fident); let r= mapped.show_primitive(ui,#config,id);  #map_post; {#on_change}; response |=r;},};
                     show_primitive_arm.push(primitive_imut.clone());
@@ -283,17 +602,49 @@ fn handle_enum(
                 to_name_arm.push(quote! { #ty :: #vident(..) => #vlabel,});
                 to_hint_arm.push(quote! { Self :: #vident(..) => #hint_top,});
 
-                show_childs_arm.push(quote! {#vident_w_inner=>{#(#fields_code)*},});
+                let access = |_: &EField, idx| format_ident!("_field_{}", idx).into_token_stream();
+                let validate = validate_all_fields(&variant.fields, variant.imut, access);
+                if let Some(validate) = validate_inline(&variant.fields, access) {
+                    validate_inline_arm.push(quote! { #vident_w_inner => { #validate }, });
+                }
+                let vname = vident.to_string();
+                validate_arm.push(quote! {
+                    #vident_w_inner => {
+                        let nested = { let mut errors = ::std::vec::Vec::new(); #(#validate)* errors };
+                        ::egui_struct::validate_nested(&mut errors, ::egui_struct::FieldPathSegment::Variant(::std::string::ToString::to_string(#vname)), nested);
+                    },
+                });
+                let conditions = field_conditions(&variant.fields);
+                show_childs_arm.push(
+                    quote! { Self:: #vident(..) =>{ #(#conditions)* if let #vident_w_inner = self {
+                        let ctx = &ctx.with_variant(#vname);
+                        #(#fields_code)*
+                    } },},
+                );
                 if variant.imut {
                     fields_code_mut = fields_code
                 }
-                show_childs_mut_arm.push(quote! { #vident_w_inner=>{#(#fields_code_mut)*},});
+                show_childs_mut_arm.push(
+                    quote! { Self:: #vident(..) =>{ #(#conditions)* if let #vident_w_inner = self {
+                        let ctx = &ctx.with_variant(#vname);
+                        #(#fields_code_mut)*
+                    } },},
+                );
+                let select_variant = variant.set_new_value(
+                    variant_new.clone(),
+                    quote! { Self:: #vident(..) },
+                    quote! { tresp.mark_changed(); },
+                );
+                let clone_new_variant = variant.set_new_value(
+                    variant_new,
+                    vident_w_inner2.clone(),
+                    quote! { #( #fields_map_eclone )* },
+                );
                 show_combobox.push(quote! {
                     let mut tresp=ui.selectable_label(matches!(self,  Self:: #vident(..)), #vlabel)#hint;
                     if tresp.clicked()
                     {
-                        *self = Self:: #vident(#(#fields_default)*);
-                        tresp.mark_changed()
+                        #select_variant
                     }
                     inner_response |=tresp;
                 });
@@ -310,10 +661,7 @@ fn handle_enum(
                         if let #vident_w_inner2=self{
                             #( #fields_map_eclone )*
                         } else {
-                            *self = Self:: #vident(#(#fields_default)*);
-                            if let #vident_w_inner2=self{
-                                #( #fields_map_eclone )*
-                            } else {::std::unreachable!()}
+                            #clone_new_variant
                         }
                     },
                 });
@@ -326,16 +674,17 @@ fn handle_enum(
                 for field in &variant.fields.fields {
                     let field_name = field.ident.as_ref().unwrap();
                     let field_type = &field.ty;
-                    fields_default.push(quote! { #field_name: #field_type::default(), });
+                    fields_default.push(quote_spanned! {field_type.span()=> #field_name: <#field_type as ::egui_struct::VariantFieldDefault>::variant_field_default(), });
                     fields_names.push(field_name);
                     let fname2 = format_ident!("_2_{}", field_name);
                     fields_names2.push(quote! { #field_name: #fname2 });
                 }
                 let vident_w_inner = quote! { Self :: #vident{#(#fields_names),*}};
+                let variant_new = variant.new_value(quote! { Self:: #vident{#(#fields_default)*} });
                 let (
                     _reset_to_struct_default,
                     fields_code,
-                    mut fields_code_mut,
+                    fields_code_mut,
                     fields_map_eclone,
                     fields_map_eeq,
                     _,
@@ -352,6 +701,10 @@ fn handle_enum(
                     vresetable,
                     Some(vident_w_inner.clone()),
                 );
+                let vprefix = prefix.clone() + &vident.to_string() + ".";
+                let fields_code = group_fields(fields_code, &variant.fields, input, &vprefix, case);
+                let mut fields_code_mut =
+                    group_fields(fields_code_mut, &variant.fields, input, &vprefix, case);
                 reset_to_struct_default |= _reset_to_struct_default;
 
                 let childs_arm = quote! { Self:: #vident{..} => true,};
@@ -359,17 +712,47 @@ fn handle_enum(
                 has_childs_mut_arm.push(childs_arm.clone());
                 to_name_arm.push(quote! { #ty :: #vident{..} => #vlabel,});
                 to_hint_arm.push(quote! { Self :: #vident{..} => #hint_top,});
-                show_childs_arm.push(quote! { #vident_w_inner => {#(#fields_code)*},});
+                let validate = validate_all_fields(&variant.fields, variant.imut, |field, _| {
+                    field.ident.to_token_stream()
+                });
+                let vname = vident.to_string();
+                validate_arm.push(quote! {
+                    #vident_w_inner => {
+                        let nested = { let mut errors = ::std::vec::Vec::new(); #(#validate)* errors };
+                        ::egui_struct::validate_nested(&mut errors, ::egui_struct::FieldPathSegment::Variant(::std::string::ToString::to_string(#vname)), nested);
+                    },
+                });
+                let conditions = field_conditions(&variant.fields);
+                show_childs_arm.push(
+                    quote! { Self:: #vident{..} =>{ #(#conditions)* if let #vident_w_inner = self {
+                        let ctx = &ctx.with_variant(#vname);
+                        #(#fields_code)*
+                    } },},
+                );
                 if variant.imut {
                     fields_code_mut = fields_code
                 }
-                show_childs_mut_arm.push(quote! { #vident_w_inner => {#(#fields_code_mut)*},});
+                show_childs_mut_arm.push(
+                    quote! { Self:: #vident{..} =>{ #(#conditions)* if let #vident_w_inner = self {
+                        let ctx = &ctx.with_variant(#vname);
+                        #(#fields_code_mut)*
+                    } },},
+                );
+                let select_variant = variant.set_new_value(
+                    variant_new.clone(),
+                    quote! { Self:: #vident{..} },
+                    quote! { tresp.mark_changed(); },
+                );
+                let clone_new_variant = variant.set_new_value(
+                    variant_new,
+                    quote! { Self::#vident{#(#fields_names2),*} },
+                    quote! { #( #fields_map_eclone )* },
+                );
                 show_combobox.push(quote! {
                     let mut tresp=ui.selectable_label(matches!(self,  Self:: #vident{..}), #vlabel)#hint;
                     if tresp.clicked()
                     {
-                        *self = Self:: #vident{#(#fields_default)*};
-                        tresp.mark_changed()
+                        #select_variant
                     }
                     inner_response |=tresp;
                 });
@@ -386,10 +769,7 @@ fn handle_enum(
                         if let Self::#vident{#(#fields_names2),*}=self{
                             #( #fields_map_eclone )*
                         } else {
-                            *self = Self:: #vident{#(#fields_default)*};
-                            if let Self::#vident{#(#fields_names2),*}=self{
-                                #( #fields_map_eclone )*
-                            } else {::std::unreachable!()}
+                            #clone_new_variant
                         }
                     },
                 });
@@ -411,6 +791,9 @@ fn handle_enum(
                         *self=Self::#vident;
                     },
                 });
+                eeq_arm.push(quote! {
+                    Self::#vident => ret = ::std::matches!(rhs, Self::#vident),
+                });
             }
         }
     }
@@ -428,11 +811,36 @@ fn handle_enum(
         .as_ref()
         .map(|x| quote!(#x))
         .unwrap_or(quote!(false));
+    let type_hint_imut = type_hint(input, &prefix, true);
+    let type_hint = type_hint(input, &prefix, false);
+    let validate_all = validate_all(
+        input,
+        quote! {
+            match self {
+                #(#validate_arm)*
+                _ => (),
+            }
+        },
+    );
+    let validate_inline = (!validate_inline_arm.is_empty()).then(|| {
+        quote! {
+            match self {
+                #(#validate_inline_arm)*
+                _ => (),
+            }
+        }
+    });
+    let validate_self = validate_self(input, validate_inline);
+    let (copy_text_imut, clipboard) = clipboard(input, quote! {}, quote! {});
 
+    let where_imut = where_clause(&input.generics, &bounds.imut);
+    let where_mutable = where_clause(&input.generics, &bounds.mutable);
+    let where_eclone = where_clause(&input.generics, &bounds.eclone);
+    let where_eeq = where_clause(&input.generics, &bounds.eeq);
     let egui_struct_imut = quote! {
-        impl #impl_generics ::egui_struct::EguiStructImut for #ty #ty_generics #where_clause {
+        impl #impl_generics ::egui_struct::EguiStructImut for #ty #ty_generics #where_imut {
             const SIMPLE_IMUT: ::std::primitive::bool = #simple;//is c-like enum
-            type ConfigTypeImut<'a> = ();
+            type ConfigTypeImut<'__eguis> = ();
             fn has_childs_imut(&self) -> ::std::primitive::bool {
                 match self{
                     #(#has_childs_arm)* //variant1=>false,
@@ -442,7 +850,13 @@ fn handle_enum(
             fn has_primitive_imut(&self) -> ::std::primitive::bool {
                 true
             }
-            fn show_childs_imut(&self, ui: &mut ::egui::Ui, indent_level: ::std::primitive::isize, mut response: ::egui::Response, _reset2: ::std::option::Option<&Self>, id: ::egui::Id) -> ::egui::Response {
+            fn value_text_imut(&self) -> ::std::option::Option<::std::string::String> {
+                ::std::option::Option::Some(match self{
+                    #(#to_name_arm)*
+                    _=>"".to_string()})
+            }
+            fn show_childs_imut(&self, ui: &mut ::egui::Ui, ctx: &::egui_struct::ViewContext<'_>, indent_level: ::std::primitive::isize, mut response: ::egui::Response, _reset2: ::std::option::Option<&Self>, left: ::std::option::Option<&Self>, id: ::egui::Id) -> ::egui::Response {
+                #![allow(unused)]
                 match self{
                     #(#show_childs_arm)*
                     _=>(),
@@ -450,11 +864,11 @@ fn handle_enum(
                 response
             }
             fn show_primitive_imut(&self, ui: &mut ::egui::Ui, _config: Self::ConfigTypeImut<'_>, id: impl ::std::hash::Hash + ::std::clone::Clone) -> ::egui::Response {
-                fn to_text(s:& #ty)-> ::std::string::String{
+                let to_text = |s: &Self| -> ::std::string::String {
                     match s{
                         #(#to_name_arm)*
                         _=>"".to_string()}
-                }
+                };
                 ui.horizontal(|ui|{
                     let mut response =ui.label(to_text(self));
                     match self{
@@ -471,6 +885,8 @@ fn handle_enum(
             fn start_collapsed_imut(&self) -> bool {
                 #start_collapsed
             }
+            #type_hint_imut
+            #copy_text_imut
         }
     };
 
@@ -485,9 +901,9 @@ fn handle_enum(
     };
 
     let egui_struct_mut = quote! {
-        impl #impl_generics ::egui_struct::EguiStruct for #ty #ty_generics #where_clause {
+        impl #impl_generics ::egui_struct::EguiStruct for #ty #ty_generics #where_mutable {
             const SIMPLE: ::std::primitive::bool = #simple;//is c-like enum
-            type ConfigType<'a> = ();
+            type ConfigType<'__eguis> = ();
             fn has_childs(&self) -> ::std::primitive::bool {
                 match self{
                     #(#has_childs_mut_arm)* //variant1=>false,
@@ -497,7 +913,12 @@ fn handle_enum(
             fn has_primitive(&self) -> ::std::primitive::bool {
                 true
             }
-            fn show_childs(&mut self, ui: &mut ::egui::Ui, indent_level: ::std::primitive::isize, mut response: ::egui::Response, reset2: ::std::option::Option<&Self>, id: ::egui::Id) -> ::egui::Response {
+            fn value_text(&self) -> ::std::option::Option<::std::string::String> {
+                ::std::option::Option::Some(match self{
+                    #(#to_name_arm)*
+                    _=>"".to_string()})
+            }
+            fn show_childs(&mut self, ui: &mut ::egui::Ui, ctx: &::egui_struct::ViewContext<'_>, indent_level: ::std::primitive::isize, mut response: ::egui::Response, reset2: ::std::option::Option<&Self>, left: ::std::option::Option<&Self>, id: ::egui::Id) -> ::egui::Response {
                 #![allow(unused)]
                 #reset_to_struct_default
                 #(#reset_to_struct_expr)*
@@ -509,11 +930,11 @@ fn handle_enum(
             }
             fn show_primitive(&mut self, ui: &mut ::egui::Ui, _config: Self::ConfigType<'_>, id: impl ::std::hash::Hash + ::std::clone::Clone) -> ::egui::Response {
                 #![allow(unused)]
-                fn to_text(s:& #ty)-> ::std::string::String{
+                let to_text = |s: &Self| -> ::std::string::String {
                     match s{
                         #(#to_name_arm)*
                         _=>"".to_string()}
-                }
+                };
                 ui.horizontal(|ui|{
                     let defspacing=ui.spacing().item_spacing.clone();
                     ui.spacing_mut().item_spacing=::egui::vec2(0.0, 0.0);
@@ -548,11 +969,15 @@ fn handle_enum(
             fn start_collapsed(&self) -> bool {
                 #start_collapsed
             }
+            #type_hint
+            #validate_all
+            #validate_self
+            #clipboard
         }
     };
 
     let eclone = quote! {
-        impl #impl_generics ::egui_struct::EguiStructClone for #ty #ty_generics #where_clause {
+        impl #impl_generics ::egui_struct::EguiStructClone for #ty #ty_generics #where_eclone {
             fn eguis_clone(&mut self, source: &Self) {
                 match source{
                     #(#eclone_arm)*
@@ -562,12 +987,13 @@ fn handle_enum(
         }
     };
     let eeq = quote! {
-        impl #impl_generics ::egui_struct::EguiStructEq for #ty #ty_generics #where_clause {
+        impl #impl_generics ::egui_struct::EguiStructEq for #ty #ty_generics #where_eeq {
             fn eguis_eq(&self, rhs: &Self) -> ::std::primitive::bool {
                 let mut ret=true;
                 match self{
                     #(#eeq_arm)*
-                    _=>(),
+                    #[allow(unreachable_patterns)]
+                    _=> ret = ::std::mem::discriminant(self) == ::std::mem::discriminant(rhs),
                 }
                 ret
             }
@@ -589,6 +1015,41 @@ fn handle_enum(
     ret
 }
 
+/// Remove parentheses around expression (closures passed as function argument are written as `(|..| ..)`)
+fn unparen(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(paren) => unparen(&paren.expr),
+        expr => expr,
+    }
+}
+
+/// Label (displayed name) of field
+fn field_label(field: &EField, idx: usize, prefix: &str, case: &Option<Converter>) -> TokenStream {
+    let Some(field_ident) = &field.ident else {
+        let label = format!("[{idx}]");
+        return quote! { #label };
+    };
+    let field_name = field_ident.to_string();
+    let label = if let Some(rename) = &field.rename {
+        rename.clone()
+    } else if let Some(case) = case {
+        case.convert(field_name.clone())
+    } else {
+        field_name.clone()
+    };
+
+    if cfg!(feature = "i18n") {
+        let key = if let Some(n) = &field.i18n {
+            n.clone()
+        } else {
+            prefix.to_string() + &field_name
+        };
+        quote! { ::rust_i18n::t!(#[allow(unused_doc_comments)]#[doc = #label] #key )}
+    } else {
+        quote! { #label }
+    }
+}
+
 fn handle_fields(
     fields: &Vec<EField>,
     prefix: String,
@@ -621,38 +1082,17 @@ fn handle_fields(
         if field.skip {
             continue;
         }
-        let lab;
+        let lab = field_label(field, idx, &prefix, case);
         let field_name;
         let name_tt;
 
         if let Some(field_ident) = &field.ident {
             field_name = field_ident.to_string();
             name_tt = field_ident.to_token_stream();
-            let label;
-            if let Some(rename) = &field.rename {
-                label = rename.clone();
-            } else if let Some(ref case) = case {
-                label = case.convert(field_name.clone());
-            } else {
-                label = field_name.clone();
-            }
-
-            if cfg!(feature = "i18n") {
-                let key = if let Some(n) = &field.i18n {
-                    n.clone()
-                } else {
-                    prefix.clone() + &field_name.to_string()
-                };
-                lab = quote! { ::rust_i18n::t!(#[allow(unused_doc_comments)]#[doc = #label] #key )};
-            } else {
-                lab = quote! { #label };
-            }
         } else {
             index = syn::Index::from(idx);
             name_tt = index.to_token_stream();
             field_name = idx.to_string();
-            let label = "[".to_string() + &field_name + "]";
-            lab = quote! { #label};
         }
         let hint = &field.hint;
         let hint = if cfg!(feature = "i18n") && !hint.is_empty() {
@@ -719,14 +1159,56 @@ fn handle_fields(
                 }
             }
         };
+        //left value of `show_diff` is passed to fields like `reset2` with `follow_arg` (but `Some(..)` allows deref coercion, eg. of `Box<T>` fields)
+        let left = if let Some(variant) = &variant {
+            quote! { match left { ::std::option::Option::Some(#variant) => ::std::option::Option::Some(#whole_ident), _ => ::std::option::Option::None } }
+        } else {
+            quote! { match left { ::std::option::Option::Some(f) => ::std::option::Option::Some(&f.#name_tt), _ => ::std::option::Option::None } }
+        };
         let start_collapsed = if let Some(x) = field.start_collapsed {
             quote!(Some(#x))
         } else {
             quote!(None)
         };
 
-        let mut field_code_imut = quote! { response |= #whole_ident.show_collapsing_inner_imut( ui, #lab, #hint, indent_level, #imconfig, ::std::option::Option::None, id, #start_collapsed);};
-        let mut field_code_mut = quote! { response |= #whole_ident.show_collapsing_inner( ui, #lab, #hint, indent_level, #config, #resetable, id, #start_collapsed);};
+        let show_imut = |target: TokenStream, left: TokenStream| {
+            let show = if let Some(widget) = &field.widget_imut {
+                let widget = unparen(widget);
+                quote! { #target.show_collapsing_widget_imut( ui, ctx, #lab, #hint, indent_level, ::std::option::Option::None, #left, id, #start_collapsed, #widget) }
+            } else {
+                quote! { #target.show_collapsing_inner_imut( ui, ctx, #lab, #hint, indent_level, #imconfig, ::std::option::Option::None, #left, id, #start_collapsed) }
+            };
+            if field.flatten {
+                quote! {
+                    if #target.has_childs_imut() && !#target.has_primitive_imut() {
+                        #target.show_childs_imut(ui, ctx, indent_level, response.clone(), ::std::option::Option::None, #left, id.with(#field_name))
+                    } else { #show }
+                }
+            } else {
+                show
+            }
+        };
+        let show_mut = |target: TokenStream, reset2: TokenStream, left: TokenStream| {
+            let show = if let Some(widget) = &field.widget {
+                let widget = unparen(widget);
+                quote! { #target.show_collapsing_widget( ui, ctx, #lab, #hint, indent_level, #reset2, #left, id, #start_collapsed, #widget) }
+            } else {
+                quote! { #target.show_collapsing_inner( ui, ctx, #lab, #hint, indent_level, #config, #reset2, #left, id, #start_collapsed) }
+            };
+            if field.flatten {
+                quote! {
+                    if #target.has_childs() && !#target.has_primitive() {
+                        #target.show_childs(ui, ctx, indent_level, response.clone(), #reset2, #left, id.with(#field_name))
+                    } else { #show }
+                }
+            } else {
+                show
+            }
+        };
+        let show = show_imut(whole_ident.clone(), left.clone());
+        let mut field_code_imut = quote! { response |= #show;};
+        let show = show_mut(whole_ident.clone(), resetable.clone(), left.clone());
+        let mut field_code_mut = quote! { response |= #show;};
         let (_ref, _ref_mut) = if variant.is_some() {
             (quote! {}, quote! {})
         } else {
@@ -734,21 +1216,30 @@ fn handle_fields(
         };
         let mut sfield = field.clone();
         let mut map_reset = quote! {};
+        //only values mapped with `map_pre_ref` can be compared with (mapped) left value
+        let mut map_left = quote! { ::std::option::Option::None };
         if let Some(map_pre_ref) = &field.map_pre_ref {
             let _ = sfield.map_pre.get_or_insert(map_pre_ref.clone());
+            map_left = quote! { #left.map(|x|#map_pre_ref(x)).as_ref() };
+            let show = show_imut(quote! {mapped}, map_left.clone());
             field_code_imut = quote! {
                 #[allow(unused_mut)]
                 let mut mapped = #map_pre_ref(#_ref #whole_ident);
-                response |=mapped .show_collapsing_inner_imut( ui, #lab, #hint, indent_level, #imconfig, ::std::option::Option::None, id, #start_collapsed);
+                response |= #show;
             };
             map_reset = quote! {#map_pre_ref};
         }
 
         if let Some(map_pre) = &sfield.map_pre {
+            let show = show_mut(
+                quote! {mapped},
+                quote! {#resetable.map(|x|#map_reset(x)).as_ref()},
+                map_left,
+            );
             field_code_mut = quote! {
                 #[allow(unused_mut)]
                 let mut mapped = #map_pre(#_ref_mut #whole_ident);
-                let r = mapped .show_collapsing_inner( ui, #lab, #hint, indent_level, #config, #resetable.map(|x|#map_reset(x)).as_ref(), id, #start_collapsed);
+                let r = #show;
                 response |= r.clone();
             };
 
@@ -757,12 +1248,18 @@ fn handle_fields(
             }
         }
         field_code_mut = quote! { #field_code_mut {#on_change}; };
+        if let Some(validate) = &field.validate {
+            field_code_mut = quote! {
+                let ctx = &ctx.with_error(#validate(#_ref #whole_ident).err());
+                #field_code_mut
+            };
+        }
 
         if let Some(expr) = &field.eeq {
             fields_map_eeq.push(quote! {#expr(#_ref #whole_ident,#_ref #whole_ident2);});
         } else {
             if let Some(map_pre_ref) = &field.map_pre_ref {
-                fields_map_eeq.push(quote! {#map_pre_ref(#_ref #whole_ident).eguis_eq(&#map_pre_ref(#_ref #whole_ident));});
+                fields_map_eeq.push(quote! {#map_pre_ref(#_ref #whole_ident).eguis_eq(&#map_pre_ref(#_ref #whole_ident2));});
             } else {
                 fields_map_eeq.push(quote! {#whole_ident.eguis_eq(#_ref #whole_ident2);});
             }
@@ -777,11 +1274,34 @@ fn handle_fields(
             fields_map_eclone.push(quote! {#whole_ident.eguis_clone(#_ref #whole_ident2);});
         }
 
-        fields_code.push(field_code_imut.clone());
+        //in enums conditions are evaluated (by `field_conditions`) before fields are borrowed
+        let (visible_ident, enabled_ident) = condition_idents(&field_name);
+        let conditional = |mut code: TokenStream| {
+            if let Some(enabled) = &field.enabled_if {
+                let enabled = variant
+                    .as_ref()
+                    .map_or(quote! {(#enabled)}, |_| quote! {#enabled_ident});
+                code = quote! {{ let ctx = &ctx.with_enabled(#enabled); #code }};
+            }
+            if let Some(visible) = &field.visible_if {
+                let visible = variant
+                    .as_ref()
+                    .map_or(quote! {(#visible)}, |_| quote! {#visible_ident});
+                code = quote! { if #visible { #code } };
+            }
+            code
+        };
+        let with_path = |code: TokenStream| {
+            quote! {{
+                let ctx = &ctx.with_field(#field_name);
+                #code
+            }}
+        };
+        fields_code.push(with_path(conditional(field_code_imut.clone())));
         if field.imut {
-            fields_code_mut.push(field_code_imut)
+            fields_code_mut.push(with_path(conditional(field_code_imut)))
         } else {
-            fields_code_mut.push(field_code_mut)
+            fields_code_mut.push(with_path(conditional(field_code_mut)))
         }
         single_field = Some(sfield);
     }
@@ -803,7 +1323,8 @@ fn handle_struct(
     case: &Option<Converter>,
     input: &EStruct,
 ) -> TokenStream {
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let bounds = Bounds::infer(input);
     let name = input.ident.clone();
     let mut resetable = input.resetable.clone();
     let reset_to_struct_expr = if let Resetable::WithExpr(expr) = &input.resetable {
@@ -827,7 +1348,7 @@ fn handle_struct(
         index,
     ) = handle_fields(
         &fields.fields,
-        prefix,
+        prefix.clone(),
         case,
         quote! {self.},
         "",
@@ -836,6 +1357,8 @@ fn handle_struct(
         resetable,
         None,
     );
+    let fields_code = group_fields(fields_code, fields, input, &prefix, case);
+    let fields_code_mut = group_fields(fields_code_mut, fields, input, &prefix, case);
 
     let reset_to_struct_default = if reset_to_struct_default {
         quote! {
@@ -848,12 +1371,14 @@ fn handle_struct(
 
     let mut show_primitive = quote! { ui.label("") };
     let mut show_primitive_imut = quote! { ui.label("") };
+    let (mut value_text_imut, mut value_text) = (quote! {}, quote! {});
+    let (mut copy_text_imut, mut copy_text) = (quote! {}, quote! {});
     let (mut simple_imut, mut simple) = (quote! {false}, quote! {false});
-    if fields.style == ast::Style::Tuple && fields_code.len() == 1 {
+    if fields.style == ast::Style::Tuple && fields.iter().filter(|f| !f.skip).count() == 1 {
         if let Some(single_field) = &single_field {
             let ty = &single_field.ty;
-            simple_imut = quote! { #ty::SIMPLE_IMUT};
-            simple = quote! { #ty::SIMPLE };
+            simple_imut = quote! { <#ty as ::egui_struct::EguiStructImut>::SIMPLE_IMUT };
+            simple = quote! { <#ty as ::egui_struct::EguiStruct>::SIMPLE };
 
             let config_imut = get_config(single_field.imconfig.clone());
             let config = get_config(single_field.config.clone());
@@ -870,6 +1395,39 @@ fn handle_struct(
                 quote! {},
                 |x| quote! { if response.changed() {#x(&mut self.#index, mapped);} },
             );
+            if single_field.map_pre.is_none() && single_field.map_pre_ref.is_none() {
+                value_text_imut = quote! {
+                    fn value_text_imut(&self) -> ::std::option::Option<::std::string::String> {
+                        if Self::SIMPLE_IMUT { self.#index.value_text_imut() } else { ::std::option::Option::None }
+                    }
+                };
+                value_text = if single_field.imut {
+                    quote! {}
+                } else {
+                    quote! {
+                        fn value_text(&self) -> ::std::option::Option<::std::string::String> {
+                            if Self::SIMPLE { self.#index.value_text() } else { ::std::option::Option::None }
+                        }
+                    }
+                };
+                copy_text_imut = quote! {
+                    fn copy_text_imut(&self) -> ::std::option::Option<::std::string::String> {
+                        if Self::SIMPLE_IMUT { self.#index.copy_text_imut() } else { ::std::option::Option::None }
+                    }
+                };
+                copy_text = if single_field.imut {
+                    quote! {}
+                } else {
+                    quote! {
+                        fn copy_text(&self) -> ::std::option::Option<::std::string::String> {
+                            if Self::SIMPLE { self.#index.copy_text() } else { ::std::option::Option::None }
+                        }
+                        fn paste_text(&mut self, text: &::std::primitive::str) -> ::std::primitive::bool {
+                            Self::SIMPLE && self.#index.paste_text(text)
+                        }
+                    }
+                };
+            }
             show_primitive_imut = quote! {
                   if Self::SIMPLE_IMUT {
                     #map_ref (&self. #index).show_primitive_imut(ui,#config_imut, id)
@@ -896,15 +1454,35 @@ fn handle_struct(
         .as_ref()
         .map(|x| quote!(#x))
         .unwrap_or(quote!(false));
+    let type_hint_imut = type_hint(input, &prefix, true);
+    let type_hint = type_hint(input, &prefix, false);
+    let access = |field: &EField, idx| {
+        let field = field
+            .ident
+            .as_ref()
+            .map_or(Index::from(idx).into_token_stream(), |x| {
+                x.into_token_stream()
+            });
+        quote! { &self.#field }
+    };
+    let validate = validate_all_fields(fields, false, access);
+    let validate_all = validate_all(input, quote! { #(#validate)* });
+    let validate_self = validate_self(input, validate_inline(fields, access));
+    let (copy_text_imut, clipboard) = clipboard(input, copy_text_imut, copy_text);
 
+    let where_imut = where_clause(&input.generics, &bounds.imut);
+    let where_mutable = where_clause(&input.generics, &bounds.mutable);
+    let where_eclone = where_clause(&input.generics, &bounds.eclone);
+    let where_eeq = where_clause(&input.generics, &bounds.eeq);
     let egui_struct_imut = quote! {
-        impl #impl_generics ::egui_struct::EguiStructImut for #name #ty_generics #where_clause {
+        impl #impl_generics ::egui_struct::EguiStructImut for #name #ty_generics #where_imut {
             const SIMPLE_IMUT: ::std::primitive::bool = #simple_imut;
-            type ConfigTypeImut<'a> = ();
+            type ConfigTypeImut<'__eguis> = ();
             fn has_childs_imut(&self) -> ::std::primitive::bool {
                !Self::SIMPLE_IMUT
             }
-            fn show_childs_imut(&self, ui: &mut ::egui::Ui, indent_level: ::std::primitive::isize, mut response: ::egui::Response, _reset2: ::std::option::Option<&Self>, id: ::egui::Id) -> ::egui::Response {
+            fn show_childs_imut(&self, ui: &mut ::egui::Ui, ctx: &::egui_struct::ViewContext<'_>, indent_level: ::std::primitive::isize, mut response: ::egui::Response, _reset2: ::std::option::Option<&Self>, left: ::std::option::Option<&Self>, id: ::egui::Id) -> ::egui::Response {
+                #![allow(unused)]
                 #(#fields_code)*
                 response
             }
@@ -914,16 +1492,20 @@ fn handle_struct(
             fn start_collapsed_imut(&self) -> bool {
                 #start_collapsed
             }
+            #type_hint_imut
+            #value_text_imut
+            #copy_text_imut
         }
     };
     let egui_struct_mut = quote! {
-        impl #impl_generics ::egui_struct::EguiStruct for #name #ty_generics #where_clause {
+        impl #impl_generics ::egui_struct::EguiStruct for #name #ty_generics #where_mutable {
             const SIMPLE: ::std::primitive::bool = #simple;
-            type ConfigType<'a> = ();
+            type ConfigType<'__eguis> = ();
             fn has_childs(&self) -> ::std::primitive::bool {
                !Self::SIMPLE
             }
-            fn show_childs(&mut self, ui: &mut ::egui::Ui, indent_level: ::std::primitive::isize, mut response: ::egui::Response, reset2: ::std::option::Option<&Self>, id: ::egui::Id) -> ::egui::Response {
+            fn show_childs(&mut self, ui: &mut ::egui::Ui, ctx: &::egui_struct::ViewContext<'_>, indent_level: ::std::primitive::isize, mut response: ::egui::Response, reset2: ::std::option::Option<&Self>, left: ::std::option::Option<&Self>, id: ::egui::Id) -> ::egui::Response {
+                #![allow(unused)]
                 #reset_to_struct_default
                 #reset_to_struct_expr
                 #(#fields_code_mut)*
@@ -935,18 +1517,23 @@ fn handle_struct(
             fn start_collapsed(&self) -> bool {
                 #start_collapsed
             }
+            #type_hint
+            #value_text
+            #validate_all
+            #validate_self
+            #clipboard
         }
     };
 
     let eclone = quote! {
-        impl #impl_generics ::egui_struct::EguiStructClone for #name #ty_generics #where_clause {
+        impl #impl_generics ::egui_struct::EguiStructClone for #name #ty_generics #where_eclone {
             fn eguis_clone(&mut self, rhs: &Self) {
                 #(#fields_map_eclone)*
             }
         }
     };
     let eeq = quote! {
-        impl #impl_generics ::egui_struct::EguiStructEq for #name #ty_generics #where_clause {
+        impl #impl_generics ::egui_struct::EguiStructEq for #name #ty_generics #where_eeq {
             fn eguis_eq(&self, rhs: &Self) -> ::std::primitive::bool {
                 let mut ret =true;
                 #( ret &= #fields_map_eeq )*
@@ -970,9 +1557,602 @@ fn handle_struct(
     ret
 }
 
-fn egui_struct_inner(input: EStruct) -> TokenStream {
+/// Names of variables holding evaluated `visible_if`/`enabled_if` of field
+fn condition_idents(field_name: &str) -> (Ident, Ident) {
+    (
+        format_ident!("__eguis_visible_{}", field_name),
+        format_ident!("__eguis_enabled_{}", field_name),
+    )
+}
+
+/// Evaluate `visible_if`/`enabled_if` of enum variant fields (must be done before fields are borrowed by match)
+fn field_conditions(fields: &ast::Fields<EField>) -> Vec<TokenStream> {
+    let mut conditions = Vec::new();
+    for (idx, field) in fields.iter().enumerate().filter(|(_, f)| !f.skip) {
+        let field_name = field
+            .ident
+            .as_ref()
+            .map_or(idx.to_string(), |x| x.to_string());
+        let (visible_ident, enabled_ident) = condition_idents(&field_name);
+        if let Some(visible) = &field.visible_if {
+            conditions.push(quote! { let #visible_ident: ::std::primitive::bool = #visible; });
+        }
+        if let Some(enabled) = &field.enabled_if {
+            conditions.push(quote! { let #enabled_ident: ::std::primitive::bool = #enabled; });
+        }
+    }
+    conditions
+}
+
+/// Code (of `validate_all()`) that checks fields with their validators & collects errors of nested fields
+fn validate_all_fields(
+    fields: &ast::Fields<EField>,
+    imut: bool,
+    access: impl Fn(&EField, usize) -> TokenStream,
+) -> Vec<TokenStream> {
+    let mut code = Vec::new();
+    for (idx, field) in fields.iter().enumerate().filter(|(_, f)| !f.skip) {
+        let field_name = field
+            .ident
+            .as_ref()
+            .map_or(idx.to_string(), |x| x.to_string());
+        let value = access(field, idx);
+        let segment = quote! { ::egui_struct::FieldPathSegment::Field(::std::string::ToString::to_string(#field_name)) };
+        let mut field_code = Vec::new();
+        if let Some(validate) = &field.validate {
+            field_code.push(quote! {
+                if let ::std::result::Result::Err(e) = #validate(#value) {
+                    errors.push((::egui_struct::FieldPath::new().with(#segment), e));
+                }
+            });
+        }
+        if !(imut || field.imut) && field.map_pre.is_none() && field.map_pre_ref.is_none() {
+            field_code.push(quote! { ::egui_struct::validate_nested(&mut errors, #segment, (#value).validate_all()); });
+        }
+        //fields hidden with `visible_if` are not checked
+        if let Some(visible) = &field.visible_if {
+            code.push(quote! { if #visible { #(#field_code)* } });
+        } else {
+            code.extend(field_code);
+        }
+    }
+    code
+}
+
+/// Generate `copy_text_imut()` & `copy_text()`/`paste_text()`/`default_value()` implementations
+///
+/// `copy_text_imut`/`copy_text` are used if `clipboard` attribute is not set
+fn clipboard(
+    input: &EStruct,
+    copy_text_imut: TokenStream,
+    copy_text: TokenStream,
+) -> (TokenStream, TokenStream) {
+    let default_value = if input.resetable == Resetable::StructDefault {
+        quote! {
+            fn default_value(&self) -> ::std::option::Option<::std::boxed::Box<Self>> {
+                ::std::option::Option::Some(::std::boxed::Box::default())
+            }
+        }
+    } else {
+        quote! {}
+    };
+    if !input.clipboard {
+        return (copy_text_imut, quote! { #copy_text #default_value });
+    }
+    (
+        quote! {
+            fn copy_text_imut(&self) -> ::std::option::Option<::std::string::String> {
+                ::egui_struct::serde_copy_text(self)
+            }
+        },
+        quote! {
+            fn copy_text(&self) -> ::std::option::Option<::std::string::String> {
+                ::egui_struct::serde_copy_text(self)
+            }
+            fn paste_text(&mut self, text: &::std::primitive::str) -> ::std::primitive::bool {
+                ::egui_struct::serde_paste_text(self, text)
+            }
+            #default_value
+        },
+    )
+}
+
+/// Generate `validate_all()` implementation (`validate_code` pushes errors to `errors`)
+fn validate_all(input: &EStruct, validate_code: TokenStream) -> TokenStream {
+    let validate_struct = input.validate.as_ref().map(|validate| {
+        quote! {
+            if let ::std::result::Result::Err(e) = #validate(self) {
+                errors.push((::egui_struct::FieldPath::new(), e));
+            }
+        }
+    });
+    quote! {
+        fn validate_all(&self) -> ::std::vec::Vec<(::egui_struct::FieldPath, ::std::string::String)> {
+            #![allow(unused)]
+            let mut errors = ::std::vec::Vec::new();
+            #validate_code
+            #validate_struct
+            errors
+        }
+    }
+}
+
+/// Validator of single field of tuple struct/variant (it is shown inline, so its errors are reported by `validate_self()` of parent)
+fn validate_inline(
+    fields: &ast::Fields<EField>,
+    access: impl Fn(&EField, usize) -> TokenStream,
+) -> Option<TokenStream> {
+    if fields.style != ast::Style::Tuple || fields.iter().filter(|f| !f.skip).count() != 1 {
+        return None;
+    }
+    let (idx, field) = fields.iter().enumerate().find(|(_, f)| !f.skip)?;
+    let validate = field.validate.as_ref()?;
+    let value = access(field, idx);
+    Some(quote! { #validate(#value)?; })
+}
+
+/// Generate `validate_self()` implementation (if struct/enum level validator or validator of inline field is set)
+fn validate_self(input: &EStruct, validate_inline: Option<TokenStream>) -> TokenStream {
+    if input.validate.is_none() && validate_inline.is_none() {
+        return quote! {};
+    }
+    let validate = input
+        .validate
+        .as_ref()
+        .map(|validate| quote! { #validate(self)?; });
+    quote! {
+        fn validate_self(&self) -> ::std::result::Result<(), ::std::string::String> {
+            #validate_inline
+            #validate
+            ::std::result::Result::Ok(())
+        }
+    }
+}
+
+/// Generated code of field, field itself & its index
+type FieldCode<'a> = (TokenStream, &'a EField, usize);
+
+/// Move code of grouped fields into collapsible groups (shown after ungrouped fields)
+fn group_fields(
+    fields_code: Vec<TokenStream>,
+    fields: &ast::Fields<EField>,
+    input: &EStruct,
+    prefix: &str,
+    case: &Option<Converter>,
+) -> Vec<TokenStream> {
+    let fields = fields.iter().enumerate().filter(|(_, f)| !f.skip);
+    let mut ungrouped = Vec::new();
+    let mut groups: Vec<(String, Vec<FieldCode>)> = input
+        .groups
+        .iter()
+        .map(|g| (g.name.clone(), Vec::new()))
+        .collect();
+    for (code, (idx, field)) in fields_code.into_iter().zip(fields) {
+        let Some(group) = &field.group else {
+            ungrouped.push((code, field, idx));
+            continue;
+        };
+        let group = group.value();
+        if let Some((_, codes)) = groups.iter_mut().find(|(name, _)| *name == group) {
+            codes.push((code, field, idx));
+        } else {
+            groups.push((group, vec![(code, field, idx)]));
+        }
+    }
+    let mut ungrouped = order_fields(ungrouped, input, prefix, case);
+    for (name, codes) in groups {
+        if codes.is_empty() {
+            continue;
+        }
+        let codes = order_fields(codes, input, prefix, case);
+        let start_collapsed = input
+            .groups
+            .iter()
+            .any(|g| g.name == name && g.start_collapsed);
+        let label = if cfg!(feature = "i18n") {
+            let key = prefix.to_string() + &Converter::new().to_case(Case::Snake).convert(&name);
+            quote! { ::rust_i18n::t!(#[allow(unused_doc_comments)]#[doc = #name] #key )}
+        } else {
+            quote! { #name }
+        };
+        ungrouped.push(quote! {
+            ::egui_struct::show_group(ui, ctx, #label, indent_level, id.with((#name, "__EguiStruct_group")), #start_collapsed, |ui, ctx, indent_level| {
+                #(#codes)*
+            });
+        });
+    }
+    ungrouped
+}
+
+/// Sort fields by `order` attribute (stable, so declaration order is kept for equal `order`)
+///
+/// If struct level `sort = "alphabetical"` is set, fields are additionaly sorted at runtime by displayed (possibly translated) label
+fn order_fields(
+    mut fields: Vec<FieldCode>,
+    input: &EStruct,
+    prefix: &str,
+    case: &Option<Converter>,
+) -> Vec<TokenStream> {
+    fields.sort_by_key(|(_, field, _)| field.order);
+    if input.sort == Sort::Declaration || fields.len() < 2 {
+        return fields.into_iter().map(|(code, _, _)| code).collect();
+    }
+    let keys = fields.iter().enumerate().map(|(pos, (_, field, idx))| {
+        let order = field.order;
+        let label = field_label(field, *idx, prefix, case);
+        quote! { (#order, (#label).to_string().to_lowercase(), #pos) }
+    });
+    let arms = fields
+        .iter()
+        .enumerate()
+        .map(|(pos, (code, _, _))| quote! { #pos => { #code } });
+    vec![quote! {
+        let mut __eguis_order = [#(#keys),*];
+        __eguis_order.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+        for (_, _, pos) in __eguis_order {
+            match pos {
+                #(#arms)*
+                _ => {}
+            }
+        }
+    }]
+}
+
+/// Collect doc comments (`///`/`#[doc = ".."]`) into single string
+fn doc_hint(attrs: &[Attribute]) -> String {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if let syn::Meta::NameValue(nv) = &attr.meta {
+            if let Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit),
+                ..
+            }) = &nv.value
+            {
+                let line = lit.value();
+                lines.push(
+                    line.strip_prefix(' ')
+                        .unwrap_or(&line)
+                        .trim_end()
+                        .to_string(),
+                );
+            }
+        }
+    }
+    lines.join("\n").trim().to_string()
+}
+
+/// If hint is not set, use doc comment as hint (unless `no_doc_hint`)
+fn apply_doc_hint(hint: &mut String, attrs: &[Attribute], no_doc_hint: bool) {
+    if hint.is_empty() && !no_doc_hint {
+        *hint = doc_hint(attrs);
+    }
+}
+
+/// Serde attributes (`#[serde(..)]`) that are honoured by `eguis`
+#[derive(Default)]
+struct SerdeAttrs {
+    rename: Option<String>,
+    rename_all: Option<LitStr>,
+    rename_all_fields: Option<LitStr>,
+    skip: bool,
+    flatten: bool,
+}
+impl SerdeAttrs {
+    fn parse(attrs: &[Attribute]) -> Self {
+        let mut ret = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+            //malformed serde attributes are reported by serde derive itself
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    ret.rename = serde_serialize_name(&meta)?.map(|x| x.value());
+                } else if meta.path.is_ident("rename_all") {
+                    ret.rename_all = serde_serialize_name(&meta)?;
+                } else if meta.path.is_ident("rename_all_fields") {
+                    ret.rename_all_fields = serde_serialize_name(&meta)?;
+                } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                    ret.skip = true;
+                } else if meta.path.is_ident("flatten") {
+                    ret.flatten = true;
+                } else if meta.input.peek(Token![=]) {
+                    meta.value()?.parse::<Expr>()?;
+                } else if !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+                    meta.input.parse::<proc_macro2::TokenTree>()?;
+                }
+                Ok(())
+            });
+        }
+        ret
+    }
+}
+
+/// Name used when serializing (`rename = "name"` OR `rename(serialize = "name")`)
+fn serde_serialize_name(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<LitStr>> {
+    if meta.input.peek(Token![=]) {
+        return Ok(Some(meta.value()?.parse()?));
+    }
+    let mut name = None;
+    meta.parse_nested_meta(|meta| {
+        let value: LitStr = meta.value()?.parse()?;
+        if meta.path.is_ident("serialize") {
+            name = Some(value);
+        }
+        Ok(())
+    })?;
+    Ok(name)
+}
+
+/// Name after applying serde `rename_all` rule (same rules as serde: `name` of variant is in PascalCase, of field in snake_case)
+fn serde_rename(rule: &LitStr, name: &str, variant: bool) -> Option<String> {
+    let snake = || {
+        if !variant {
+            return name.to_string();
+        }
+        let mut snake = String::new();
+        for (i, ch) in name.char_indices() {
+            if i > 0 && ch.is_uppercase() {
+                snake.push('_');
+            }
+            snake.push(ch.to_ascii_lowercase());
+        }
+        snake
+    };
+    let pascal = || {
+        if variant {
+            return name.to_string();
+        }
+        let mut pascal = String::new();
+        let mut capitalize = true;
+        for ch in name.chars() {
+            if ch == '_' {
+                capitalize = true;
+            } else if capitalize {
+                pascal.push(ch.to_ascii_uppercase());
+                capitalize = false;
+            } else {
+                pascal.push(ch);
+            }
+        }
+        pascal
+    };
+    Some(match rule.value().as_str() {
+        "lowercase" if variant => name.to_ascii_lowercase(),
+        "lowercase" => name.to_string(),
+        "UPPERCASE" => name.to_ascii_uppercase(),
+        "PascalCase" => pascal(),
+        "camelCase" => {
+            let pascal = pascal();
+            let mut chars = pascal.chars();
+            chars.next().map_or(String::new(), |first| {
+                first.to_ascii_lowercase().to_string() + chars.as_str()
+            })
+        }
+        "snake_case" => snake(),
+        "SCREAMING_SNAKE_CASE" => snake().to_ascii_uppercase(),
+        "kebab-case" => snake().replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => snake().to_ascii_uppercase().replace('_', "-"),
+        _ => return None,
+    })
+}
+
+/// Use serde attributes where no `eguis` override is present (if enabled by `serde` attribute or `serde-attrs` feature)
+///
+/// serde `rename_all` is applied only if `eguis(rename_all)` is not set (it renames variants of enum or fields of struct/variant, like in serde)
+fn apply_serde_attrs(input: &mut EStruct) {
+    if !(input.serde || cfg!(feature = "serde-attrs")) {
+        return;
+    }
+    let serde = SerdeAttrs::parse(&input.attrs);
+    let use_rename_all = input.rename_all.is_none();
+    let apply_fields = |fields: &mut ast::Fields<EField>, rename_all: Option<&LitStr>| {
+        for field in fields.fields.iter_mut() {
+            let serde = SerdeAttrs::parse(&field.attrs);
+            field.rename = field.rename.take().or(serde.rename).or_else(|| {
+                let ident = field.ident.as_ref()?.unraw().to_string();
+                serde_rename(rename_all.filter(|_| use_rename_all)?, &ident, false)
+            });
+            field.skip |= serde.skip;
+            field.flatten |= serde.flatten;
+        }
+    };
+    match &mut input.data {
+        ast::Data::Enum(variants) => {
+            for variant in variants.iter_mut() {
+                let vserde = SerdeAttrs::parse(&variant.attrs);
+                variant.rename = variant.rename.take().or(vserde.rename).or_else(|| {
+                    let ident = variant.ident.unraw().to_string();
+                    serde_rename(
+                        serde.rename_all.as_ref().filter(|_| use_rename_all)?,
+                        &ident,
+                        true,
+                    )
+                });
+                variant.skip |= vserde.skip;
+                let rename_all = vserde
+                    .rename_all
+                    .as_ref()
+                    .or(serde.rename_all_fields.as_ref());
+                apply_fields(&mut variant.fields, rename_all);
+            }
+        }
+        ast::Data::Struct(fields) => apply_fields(fields, serde.rename_all.as_ref()),
+    }
+}
+
+fn apply_doc_hints(input: &mut EStruct) {
+    let no_doc_hint = input.no_doc_hint;
+    apply_doc_hint(&mut input.hint, &input.attrs, no_doc_hint);
+    let apply_fields = |fields: &mut ast::Fields<EField>| {
+        for field in fields.fields.iter_mut() {
+            apply_doc_hint(
+                &mut field.hint,
+                &field.attrs,
+                no_doc_hint || field.no_doc_hint,
+            );
+        }
+    };
+    match &mut input.data {
+        ast::Data::Enum(variants) => {
+            for variant in variants.iter_mut() {
+                apply_doc_hint(
+                    &mut variant.hint,
+                    &variant.attrs,
+                    no_doc_hint || variant.no_doc_hint,
+                );
+                apply_fields(&mut variant.fields);
+            }
+        }
+        ast::Data::Struct(fields) => apply_fields(fields),
+    }
+}
+
+/// Generate `type_hint()`/`type_hint_imut()` implementation (if struct/enum has hint)
+fn type_hint(input: &EStruct, prefix: &str, imut: bool) -> TokenStream {
+    let hint = &input.hint;
+    if hint.is_empty() {
+        return quote! {};
+    }
+    let hint = if cfg!(feature = "i18n") {
+        let key = prefix.to_string() + "__hint";
+        quote! { ::rust_i18n::t!(#[allow(unused_doc_comments)]#[doc = #hint] #key )}
+    } else {
+        quote! { #hint }
+    };
+    let name = if imut {
+        format_ident!("type_hint_imut")
+    } else {
+        format_ident!("type_hint")
+    };
+    quote! {
+        fn #name(&self) -> ::std::string::String {
+            ::std::string::ToString::to_string(&#hint)
+        }
+    }
+}
+
+fn validate_fields(
+    fields: &ast::Fields<EField>,
+    imut: bool,
+    groups: &[EGroup],
+    errors: &mut darling::error::Accumulator,
+) {
+    //single field of tuple struct/variant is shown inline (in the same row as parent)
+    let inline =
+        fields.style == ast::Style::Tuple && fields.iter().filter(|f| !f.skip).count() == 1;
+    for field in &fields.fields {
+        if inline && !field.skip {
+            for condition in [&field.visible_if, &field.enabled_if].into_iter().flatten() {
+                errors.push(
+                    darling::Error::custom(
+                        "`visible_if`/`enabled_if` can not be used on single field of tuple struct/variant (it is shown in the same row as struct/variant itself)",
+                    )
+                    .with_span(condition),
+                );
+            }
+        }
+        if let Some(map_post) = &field.map_post {
+            if field.map_pre.is_none() && field.map_pre_ref.is_none() {
+                errors.push(
+                    darling::Error::custom(
+                        "`map_post` requires `map_pre` (or `map_pre_ref`) to be set",
+                    )
+                    .with_span(map_post),
+                );
+            }
+        }
+        if let Some(group) = &field.group {
+            if !groups.is_empty() && !groups.iter().any(|g| g.name == group.value()) {
+                errors.push(
+                    darling::Error::custom(format!(
+                        "group `{}` is not declared (declared groups: {})",
+                        group.value(),
+                        groups
+                            .iter()
+                            .map(|g| format!("`{}`", g.name))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+                    .with_span(group),
+                );
+            }
+        }
+        for (widget, config) in [
+            (&field.widget, &field.config),
+            (&field.widget_imut, &field.imconfig),
+        ] {
+            if let (Some(_), Some(config)) = (widget, config) {
+                errors.push(
+                    darling::Error::custom("config is not used when custom widget is set")
+                        .with_span(&config.0),
+                );
+            }
+        }
+        if imut || field.imut {
+            for on_change in [&field.on_change, &field.on_change_struct]
+                .into_iter()
+                .flatten()
+            {
+                errors.push(
+                    darling::Error::custom(
+                        "`on_change`/`on_change_struct` can not be used on `imut` field (it is never changed)",
+                    )
+                    .with_span(on_change),
+                );
+            }
+        }
+    }
+}
+
+/// Check attributes for errors, that would otherwise result in confusing errors in generated code
+fn validate(input: &EStruct) -> darling::Result<()> {
+    let mut errors = darling::Error::accumulator();
+    match &input.data {
+        ast::Data::Enum(variants) => {
+            for variant in variants {
+                validate_fields(&variant.fields, variant.imut, &input.groups, &mut errors);
+                if let Some(other) = variant
+                    .default
+                    .as_ref()
+                    .and_then(|expr| expr_variant(expr, &input.ident))
+                {
+                    if *other != variant.ident {
+                        errors.push(
+                            darling::Error::custom(format!(
+                                "`default` of variant `{}` has to create the same variant",
+                                variant.ident
+                            ))
+                            .with_span(other),
+                        );
+                    }
+                }
+                if let (Some(_), Some(constructor)) = (&variant.default, &variant.constructor) {
+                    errors.push(
+                        darling::Error::custom(
+                            "attributes `default` and `constructor` are mutually exclusive",
+                        )
+                        .with_span(constructor),
+                    );
+                }
+            }
+        }
+        ast::Data::Struct(fields) => validate_fields(fields, false, &input.groups, &mut errors),
+    }
+    errors.finish()
+}
+
+fn egui_struct_inner(mut input: EStruct) -> TokenStream {
+    apply_serde_attrs(&mut input);
+    let mut errors = darling::Error::accumulator();
+    let case = input
+        .rename_all
+        .as_ref()
+        .and_then(|x| errors.handle(parse_case_name(x)));
+    errors.handle(validate(&input));
+    if let Err(errors) = errors.finish() {
+        return errors.write_errors();
+    }
+
+    apply_doc_hints(&mut input);
     let mut prefix = String::new();
-    let case = input.rename_all.as_ref().map(|x| parse_case_name(&x));
 
     if cfg!(feature = "i18n") {
         if let Some(p) = &input.prefix {
@@ -1006,25 +2186,50 @@ fn egui_struct_inner(input: EStruct) -> TokenStream {
 /// - enum/struct level:
 ///   - `rename_all = "str"`- renames all fields/variants to selected case (recognized values: `"Upper"`, `"Lower"`, `"Title"`, `"Toggle"`, `"Camel"`, `"Pascal"`, `"UpperCamel"`, `"Snake"`, `"UpperSnake"`, `"ScreamingSnake"`, `"Kebab"`, `"Cobol"`, `"UpperKebab"`, `"Train"`, `"Flat"`, `"UpperFlat"`, `"Alternating"`, `"Sentence"`)
 ///   - `prefix = "str"`- add this prefix when generating `rust-i18n` keys
+///   - `hint ="str"` - on hover hint for fields of this type (that does not have own hint); if not set, doc comment (`///`) of struct/enum is used
+///   - `no_doc_hint` - do not use doc comments as hints (for struct/enum itself & all fields/variants)
 ///   - `no_mut` - do not generate `EguiStruct` implementation
 ///   - `no_eclone` - do not generate `EguiStructClone` implementation
 ///   - `no_eeq` - do not generate `EguiStructEq` implementation
 ///   - `start_collapsed = "Expr"` - sets `start_collapsed()` implementation (should return `bool`; can use `self`)
+///   - `bound = "T: Trait, .."` - where clause predicates used instead of inferred ones (by default, for each field type that uses generic type param, bound on needed trait is added, eg. `Vec<T>: EguiStruct`)
+///   - `group(name = "str", start_collapsed = bool)` - declare group of fields (see field level `group`); groups are shown in declaration order (if no group is declared, groups are shown in order of first use)
+///   - `sort = "alphabetical"` - fields are sorted (at runtime, case-insensitive) by displayed label (fields with lower `order` are still shown first)
+///   - `serde` - use serde attributes (`rename`, `rename_all`, `rename_all_fields`, `skip`, `skip_serializing`, `flatten`) of struct/fields/variants if `eguis` does not override them (labels are same as serialized names; always enabled with `serde-attrs` feature)
+///   - `validate = path` - function (signature: `fn(&Self) -> Result<(), String>`) that checks whole struct/enum; row of invalid struct/enum is highlighted (error is shown on hover of ⚠ next to label) & error is returned by `validate_all()`/`validate_self()`
 ///   - `resetable = "val"` OR `resetable(with_expr = Expr)` - all fields/variants will be resetable according to provieded value (val: `"not_resetable"`, `"field_default"`, `"struct_default"`, `"follow_arg"`(use value passed on runtime through reset2 arg))
+///     - with `"struct_default"` "Reset to default" in row context menu is also available for struct/enum itself
+///   - `clipboard` - "Copy value"/"Paste value" (row context menu) copy whole struct/enum as RON text (requires `serde` feature & type implementing `serde::Serialize` + `serde::Deserialize`)
 /// - variant level:
 ///   - `rename ="str"`- Name of the field to be displayed on UI labels or variantName in i18n key
 ///   - `skip` - Don't generate code for the given variant
-///   - `hint ="str"` - add on hover hint
+///   - `hint ="str"` - add on hover hint (if not set, doc comment (`///`) is used)
+///   - `no_doc_hint` - do not use doc comment as hint
 ///   - `imut` - variant will be shown as immutable
 ///   - `i18n ="i18n_key"`- normally i18n keys are in format "prefix.enumName.variantName", override this with "i18n_key"
 ///   - `resetable`- overides enum/struct level resetable
+///   - `default = Expr` - value (of type `Self`, has to be the same variant) that enum is set to when variant is selected in combobox
+///     - if it creates another variant, value is left unchanged (selecting variant does nothing)
+///     - by default all fields are set to `Default::default()`, so this allows variants with fields that does not implement `Default`
+///   - `constructor = path` - same as `default`, but takes function path (signature: `fn() -> Self`)
 /// - field level
-///   - `rename`, `skip`, `hint`, `imut`, `i18n`- see variant level
+///   - `rename`, `skip`, `hint`, `no_doc_hint`, `imut`, `i18n`- see variant level
 ///   - `resetable`- overides enum/struct & variant level resetable
 ///   - `on_change = "expr"`- Use function (`expr`: closure surounded by `()` OR function path) callback (when value has been changed; signature: `fn(&mut field_type)`)
 ///   - `on_change_struct = "expr"`- Similar to `on_change` but takes whole struct: signature: `fn(&mut self)`
-///   - `config`- pass format/config object to customise how field is displayed
+///   - `config = Expr`- pass format/config object ([`EguiStruct::ConfigType`]) to customise how field is displayed (eg. `config = Slider(0, 10)`; string form `config = "Slider(0, 10)"` is also accepted)
 ///   - `start_collapsed = true/false` - field always starts collapsed/uncollapsed (overides fields `start_collapsed()` return)
+///   - `flatten` - fields of nested struct are shown directly in parent rows (at the same indent level, without own collapsible row)
+///     - only applies when field has childs & no primitive (eg. enums are still shown normally)
+///   - `group = "str"` - show field inside collapsible group (groups are shown after ungrouped fields; if any group is declared, has to be one of declared groups; i18n key: "prefix.structName.group_name" (group name converted to snake case))
+///   - `order = N` - fields are shown in order of this value (default `0`; fields with equal value are shown in declaration order)
+///   - `visible_if = Expr` - field is shown only if expression (should return `bool`; can use `self`) is true
+///   - `enabled_if = Expr` - field (and its childs) is shown greyed out & can not be edited if expression (should return `bool`; can use `self`) is false
+///     - `visible_if`/`enabled_if` can not be used on single field of tuple struct/variant (it is shown inline, in the same row as struct/variant)
+///   - `validate = path` - function (signature: `fn(&field_type) -> Result<(), String>`) that checks field value; row of invalid field is highlighted (error is shown on hover of ⚠ next to label) & error is returned by `validate_all()` (if field is not hidden by `visible_if`)
+///   - `widget = path` - function (signature: `fn(&mut field_type, &mut Ui, Id) -> Response`) that draws primitive cell instead of `show_primitive()`
+///     - label, hint, reset button, `on_change` & childs are handled as usual (`config` is not used)
+///     - `widget_imut = path` - same for imutable view (signature: `fn(&field_type, &mut Ui, Id) -> Response`)
 ///   - `map_pre`- Expression (closure surounded by `()` OR function path) called to map field to another type before displaying
 ///     - this allows displaying fields that does not implement EguiStruct or overiding how field is shown
 ///     - function shall take `& field_type` or `&mut field_type` AND return either mutable reference or owned value of selected type (that implements `EguiStruct`)
@@ -1040,10 +2245,14 @@ fn egui_struct_inner(input: EStruct) -> TokenStream {
 ///     - if either `field_type : EguiStructEq` OR `map_pre_ref` is specified can be unused
 ///   - `eclone`- override `eguis_eclone` function for field (signature fn(&mut field_type, &field_type))
 ///     - if `field_type : EguiStructClone` can be unused
+///   - `bound = "T: Trait, .."` - where clause predicates used instead of ones inferred from this field type
 #[proc_macro_derive(EguiStruct, attributes(eguis, eguisM))]
 pub fn egui_struct(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse_macro_input!(input as DeriveInput);
-    let mut input = EStruct::from_derive_input(&ast).unwrap();
+    let mut input = match EStruct::from_derive_input(&ast) {
+        Ok(input) => input,
+        Err(e) => return e.write_errors().into(),
+    };
     input.no_imut = true;
     let toks = egui_struct_inner(input);
     debug_print_generated(&ast, &toks);
@@ -1067,23 +2276,42 @@ pub fn egui_struct(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// - enum/struct level:
 ///   - `rename_all = "str"`- renames all fields/variants to selected case (recognized values: `"Upper"`, `"Lower"`, `"Title"`, `"Toggle"`, `"Camel"`, `"Pascal"`, `"UpperCamel"`, `"Snake"`, `"UpperSnake"`, `"ScreamingSnake"`, `"Kebab"`, `"Cobol"`, `"UpperKebab"`, `"Train"`, `"Flat"`, `"UpperFlat"`, `"Alternating"`, `"Sentence"`)
 ///   - `prefix = "str"`- add this prefix when generating `rust-i18n` keys
+///   - `hint ="str"` - on hover hint for fields of this type (that does not have own hint); if not set, doc comment (`///`) of struct/enum is used
+///   - `no_doc_hint` - do not use doc comments as hints (for struct/enum itself & all fields/variants)
 ///   - `start_collapsed = "Expr"` - sets `EguiStructImut::start_collapsed_imut()` implementation (should return `bool`; can use `self`)
+///   - `bound = "T: Trait, .."` - where clause predicates used instead of inferred ones (by default, for each field type that uses generic type param, bound `field_type: EguiStructImut` is added)
+///   - `group(name = "str", start_collapsed = bool)` - declare group of fields (see field level `group`); groups are shown in declaration order (if no group is declared, groups are shown in order of first use)
+///   - `sort = "alphabetical"` - fields are sorted (at runtime, case-insensitive) by displayed label (fields with lower `order` are still shown first)
+///   - `serde` - see `EguiStruct` derive
+///   - `clipboard` - see `EguiStruct` derive
 /// - variant level:
 ///   - `rename ="str"`- Name of the field to be displayed on UI labels or variantName in i18n key
 ///   - `skip` - Don't generate code for the given variant
-///   - `hint ="str"` - add on hover hint
+///   - `hint ="str"` - add on hover hint (if not set, doc comment (`///`) is used)
+///   - `no_doc_hint` - do not use doc comment as hint
 ///   - `i18n ="i18n_key"`- normally i18n keys are in format "prefix.enumName.variantName", override this with "i18n_key"
 /// - field level
-///   - `rename`, `skip`, `hint`, `i18n`- see variant level
-///   - `imconfig`- pass format/config object([`EguiStructImut::ConfigTypeImut`) to customise how field is displayed
+///   - `rename`, `skip`, `hint`, `no_doc_hint`, `i18n`- see variant level
+///   - `imconfig = Expr`- pass format/config object ([`EguiStructImut::ConfigTypeImut`]) to customise how field is displayed (string form `imconfig = "Expr"` is also accepted)
 ///   - `start_collapsed = true/false` - field always starts collapsed/uncollapsed (overides fields `EguiStructImut::start_collapsed_imut()` return)
+///   - `flatten` - fields of nested struct are shown directly in parent rows (at the same indent level, without own collapsible row)
+///   - `group = "str"` - show field inside collapsible group (groups are shown after ungrouped fields; if any group is declared, has to be one of declared groups; i18n key: "prefix.structName.group_name" (group name converted to snake case))
+///   - `order = N` - fields are shown in order of this value (default `0`; fields with equal value are shown in declaration order)
+///   - `visible_if = Expr` - field is shown only if expression (should return `bool`; can use `self`) is true
+///   - `enabled_if = Expr` - field (and its childs) is shown greyed out & can not be edited if expression (should return `bool`; can use `self`) is false
+///     - `visible_if`/`enabled_if` can not be used on single field of tuple struct/variant (it is shown inline, in the same row as struct/variant)
+///   - `widget_imut = path` - function (signature: `fn(&field_type, &mut Ui, Id) -> Response`) that draws primitive cell instead of `show_primitive_imut()` (`imconfig` is not used)
 ///   - `map_pre_ref`- Expression (closure surounded by `()` OR function path) called to map field to another type before displaying
 ///     - this allows displaying fields that does not implement `EguiStructImut` or overiding how field is shown
 ///     - function shall take `&field_type` AND return either reference or owned value of selected type (that implements `EguiStructImut`)
+///   - `bound = "T: Trait, .."` - where clause predicates used instead of ones inferred from this field type
 #[proc_macro_derive(EguiStructImut, attributes(eguis, eguisI))]
 pub fn egui_struct_imut(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse_macro_input!(input as DeriveInput);
-    let mut input = EStruct::from_derive_input(&ast).unwrap();
+    let mut input = match EStruct::from_derive_input(&ast) {
+        Ok(input) => input,
+        Err(e) => return e.write_errors().into(),
+    };
     input.no_eclone = true;
     input.no_eeq = true;
     input.no_mut = true;
@@ -1106,15 +2334,15 @@ fn debug_print_generated(ast: &DeriveInput, toks: &TokenStream) {
         }
     }
 }
-fn get_config(config: Option<String>) -> TokenStream {
-    config
-        .unwrap_or("::std::default::Default::default()".to_string())
-        .parse()
-        .unwrap()
+fn get_config(config: Option<ConfigExpr>) -> TokenStream {
+    match config {
+        Some(ConfigExpr(config)) => config.into_token_stream(),
+        None => quote! { ::std::default::Default::default() },
+    }
 }
-fn parse_case_name(case_name: &str) -> Converter {
+fn parse_case_name(case_name: &LitStr) -> darling::Result<Converter> {
     let conv = Converter::new();
-    match case_name {
+    Ok(match case_name.value().as_str() {
         "Upper" => conv.to_case(Case::Upper),
         "Lower" => conv.to_case(Case::Lower),
         "Title" => conv.to_case(Case::Title),
@@ -1133,6 +2361,12 @@ fn parse_case_name(case_name: &str) -> Converter {
         "UpperFlat" => conv.to_case(Case::UpperFlat),
         "Alternating" => conv.to_case(Case::Alternating),
         "Sentence" => conv.set_pattern(Pattern::Sentence).set_delim(" "),
-        _ => panic!("Unrecognized case name: {}", case_name),
-    }
+        other => {
+            return Err(darling::Error::custom(format!(
+                "Unrecognized case name: `{}` (recognized values: \"Upper\", \"Lower\", \"Title\", \"Toggle\", \"Camel\", \"Pascal\", \"UpperCamel\", \"Snake\", \"UpperSnake\", \"ScreamingSnake\", \"Kebab\", \"Cobol\", \"UpperKebab\", \"Train\", \"Flat\", \"UpperFlat\", \"Alternating\", \"Sentence\")",
+                other
+            ))
+            .with_span(case_name))
+        }
+    })
 }
@@ -2,8 +2,13 @@
 //!
 //! See [demo](https://github.com/PingPongun/egui_struct/tree/master/demo)
 
-use egui::{Button, Grid, Id, Response, ScrollArea, Ui, Widget, WidgetText};
+use egui::{
+    Button, Grid, Id, Key, KeyboardShortcut, Label, Modifiers, Response, ScrollArea, Ui, Widget,
+    WidgetText,
+};
 pub use egui_struct_macros::*;
+use std::cell::RefCell;
+use std::collections::HashSet;
 use std::hash::Hash;
 use std::ops::{Deref, DerefMut};
 
@@ -25,8 +30,8 @@ use egui27 as egui;
 use egui28 as egui;
 
 macro_rules! generate_show {
-    ($top_name:ident, $collapsing_name:ident, $show_collapsing_inner:ident, $primitive_name:ident, $childs_name:ident, $start_collapsed:ident,
-         $typ:ty, $config:ident, $COLUMN_COUNT:ident, $SIMPLE:ident, $has_childs:ident, $has_primitive:ident) => {
+    ($top_name:ident, $top_view_name:ident, $collapsing_name:ident, $show_collapsing_inner:ident, $show_collapsing_widget:ident, $primitive_name:ident, $childs_name:ident, $start_collapsed:ident, $type_hint:ident,
+         $typ:ty, $config:ident, $COLUMN_COUNT:ident, $SIMPLE:ident, $has_childs:ident, $has_primitive:ident, $value_text:ident, $copy_text:ident) => {
         /// Type that will pass some data to customise how data is shown, in most cases this will be () (eg. for numerics this is [ConfigNum])
         type $config<'a>: Default;
 
@@ -46,8 +51,20 @@ macro_rules! generate_show {
             !self.$has_childs()
         }
 
+        /// Text representation of value, that is matched by search filter (if matching values is enabled)
+        fn $value_text(&self) -> Option<String> {
+            None
+        }
+
+        /// Text copied by "Copy value" (in row context menu); defaults to value matched by search filter
+        fn $copy_text(&self) -> Option<String> {
+            self.$value_text()
+        }
+
         /// Show data in view contained ScrollArea&Grid
         ///
+        /// To configure (or omit) ScrollArea & Grid or show search filter box above data use [EguiStructView]
+        ///
         /// You should rather not need to override default impl
         fn $top_name(
             self: $typ,
@@ -58,93 +75,261 @@ macro_rules! generate_show {
         where
             Self: 'static,
         {
-            let label: WidgetText = label.into();
-            let id =
-                ui.make_persistent_id((label.text().to_string(), std::any::TypeId::of::<Self>()));
-            ScrollArea::vertical()
-                .show(ui, |ui| {
-                    Grid::new(id)
-                        .num_columns(Self::$COLUMN_COUNT)
+            self.$top_view_name(
+                ui,
+                EguiStructView::new(label),
+                reset2,
+                None,
+                &ViewState::default(),
+            )
+        }
+
+        #[doc(hidden)]
+        fn $top_view_name(
+            self: $typ,
+            ui: &mut Ui,
+            view: EguiStructView,
+            reset2: Option<&Self>,
+            left: Option<&Self>,
+            state: &ViewState,
+        ) -> Response
+        where
+            Self: 'static,
+        {
+            let label = view.label.clone();
+            let id = match view.options.id_salt {
+                Some(salt) => ui.make_persistent_id((salt, std::any::TypeId::of::<Self>())),
+                None => ui
+                    .make_persistent_id((label.text().to_string(), std::any::TypeId::of::<Self>())),
+            };
+            let ctx = ViewContext::new(&view.options, state);
+            let show = |ui: &mut Ui| {
+                view.scroll_area(ui, |ui| {
+                    view.grid(id, Self::$COLUMN_COUNT)
                         .show(ui, |ui| {
-                            self.$collapsing_name(ui, label, "", -1, Default::default(), reset2, id)
+                            self.$collapsing_name(
+                                ui,
+                                &ctx,
+                                label,
+                                "",
+                                -1,
+                                Default::default(),
+                                reset2,
+                                left,
+                                id,
+                            )
                         })
                         .inner
                 })
-                .inner
+            };
+            if view.options.search {
+                show_search_filter(ui, id, state, show)
+            } else {
+                show(ui)
+            }
         }
 
         #[doc(hidden)]
+        #[allow(clippy::too_many_arguments)]
         fn $show_collapsing_inner(
             self: $typ,
             ui: &mut Ui,
+            ctx: &ViewContext,
             label: impl Into<WidgetText> + Clone,
             hint: impl Into<WidgetText> + Clone,
             indent_level: isize,
             config: Self::$config<'_>,
+            reset2: Option<&Self>,
+            left: Option<&Self>,
+            parent_id: Id,
+            start_collapsed: Option<bool>,
+        ) -> Response {
+            self.$show_collapsing_widget(
+                ui,
+                ctx,
+                label,
+                hint,
+                indent_level,
+                reset2,
+                left,
+                parent_id,
+                start_collapsed,
+                |s, ui, id| s.$primitive_name(ui, config, id),
+            )
+        }
+
+        /// Same as show_collapsing_inner, but primitive cell is drawn by `widget` (used by `#[eguis(widget = ..)]`)
+        #[doc(hidden)]
+        #[allow(clippy::too_many_arguments)]
+        fn $show_collapsing_widget(
+            self: $typ,
+            ui: &mut Ui,
+            ctx: &ViewContext,
+            label: impl Into<WidgetText> + Clone,
+            hint: impl Into<WidgetText> + Clone,
+            indent_level: isize,
             _reset2: Option<&Self>,
+            left: Option<&Self>,
             parent_id: Id,
             start_collapsed: Option<bool>,
+            widget: impl FnOnce($typ, &mut Ui, Id) -> Response,
         ) -> Response {
-            let mut collapsed = false;
             let has_childs = self.$has_childs();
-            let id = parent_id.with(label.clone().into().text());
-            ui.horizontal(|ui| {
-                if indent_level >= 0 {
-                    for _ in 0..indent_level {
-                        ui.separator();
+            let id = ctx.row_id(parent_id, || label.clone().into().text().to_string());
+            let mut hint: WidgetText = hint.into();
+            if hint.is_empty() {
+                hint = self.$type_hint().into();
+            }
+            let enabled = ctx.enabled();
+            let options = ctx.options();
+            let label: WidgetText = label.into();
+            let filter = filter_row(ctx, id, indent_level, &label, &hint, || self.$value_text());
+            let command = if has_childs {
+                collapse_all_command(ui, ctx, id)
+            } else {
+                None
+            };
+            if let (Some(collapse), true) = (command, indent_level >= 0) {
+                set_collapsed(ui, ctx.options(), id, collapse);
+            }
+            let diff = ctx.state.diff;
+            //`Some(true)` if row differs from left value (unknown for imutable rows)
+            macro_rules! differs {
+                (show_collapsing_imut) => {
+                    None
+                };
+                (show_collapsing) => {
+                    left.filter(|_| diff.is_some()).map(|l| !l.eguis_eq(self))
+                };
+            }
+            let differs: Option<bool> = differs! {$collapsing_name};
+            let hide_equal = diff == Some(true) && indent_level >= 0 && differs == Some(false);
+            if !filter.draw || ctx.hidden || hide_equal {
+                let mut ret = ui.interact(egui::Rect::NOTHING, id, egui::Sense::hover());
+                if has_childs {
+                    let ctx = ctx.subtree(id, filter, true, command);
+                    ret = self.$childs_name(ui, &ctx, indent_level + 1, ret, _reset2, left, id);
+                }
+                return ret;
+            }
+            #[allow(unused_mut)]
+            let mut changed = false;
+            let differs = differs == Some(true);
+            //validation error of field (set by parent) or of whole struct/enum
+            macro_rules! error {
+                (show_collapsing_imut) => {
+                    None
+                };
+                (show_collapsing) => {
+                    ctx.error.clone().or_else(|| self.validate_self().err())
+                };
+            }
+            let error: Option<String> = error! {$collapsing_name};
+            let background =
+                (differs || error.is_some()).then(|| ui.painter().add(egui::Shape::Noop));
+            let start_collapsed = has_childs.then(|| {
+                start_collapsed.unwrap_or_else(|| {
+                    options.collapsed_at(indent_level) || self.$start_collapsed()
+                })
+            });
+            let copy_text = self.$copy_text();
+            let (label_rect, collapsed) = show_row_label(
+                ui,
+                ctx,
+                id,
+                indent_level,
+                label,
+                hint,
+                &filter,
+                start_collapsed,
+                error.as_deref(),
+                |ui| {
+                    show_copy_menu(ui, copy_text);
+                    macro_rules! edit_menu {
+                        (show_collapsing_imut) => {};
+                        (show_collapsing) => {
+                            changed = ui
+                                .add_enabled_ui(enabled, |ui| show_edit_menu(ui, id, self, _reset2))
+                                .inner;
+                        };
                     }
-                    if has_childs {
-                        let id = id.with("__EguiStruct_collapsing_state");
-                        collapsed = ui.data_mut(|d| {
-                            d.get_temp_mut_or_insert_with(id, || {
-                                start_collapsed.unwrap_or(self.$start_collapsed())
-                            })
-                            .clone()
-                        });
-                        let icon = if collapsed { "⏵" } else { "⏷" };
-                        if Button::new(icon).frame(false).small().ui(ui).clicked() {
-                            ui.data_mut(|d| d.insert_temp(id, !collapsed));
-                        }
+                    edit_menu! {$collapsing_name}
+                },
+            );
+            if diff.is_some() {
+                let left_text = left.and_then(|l| {
+                    if l.$has_childs() {
+                        l.$value_text()
+                    } else {
+                        l.$copy_text()
                     }
+                });
+                if show_diff_cell(ui, ctx, left_text, enabled, differs && enabled) {
+                    macro_rules! copy_left {
+                        (show_collapsing_imut) => {};
+                        (show_collapsing) => {
+                            if let Some(left) = left {
+                                self.eguis_clone(left);
+                                changed = true;
+                            }
+                        };
+                    }
+                    copy_left! {$collapsing_name}
                 }
-                let mut lab = ui.label(label.into());
-                let hint = hint.into();
-                if !hint.is_empty() {
-                    lab = lab.on_hover_text(hint);
-                }
-                lab
-            });
+            }
 
             let mut ret = ui
                 .horizontal(|ui| {
-                    let id = id.with("__EguiStruct_primitive");
-                    #[allow(unused_mut)]
-                    let mut ret = self.$primitive_name(ui, config, id);
-                    macro_rules! reset {
-                        (show_collapsing_imut) => {
-                            ret
-                        };
-                        (show_collapsing) => {
-                            if let Some(reset2) = _reset2 {
-                                if !reset2.eguis_eq(self) {
-                                    let mut r = ui.button("⟲");
-                                    if r.clicked() {
-                                        self.eguis_clone(reset2);
-                                        r.mark_changed();
+                    if let Some(width) = options.value_width {
+                        ui.set_min_width(width);
+                    }
+                    ui.add_enabled_ui(enabled, |ui| {
+                        let id = id.with("__EguiStruct_primitive");
+                        #[allow(unused_mut)]
+                        let mut ret = widget(self, ui, id);
+                        macro_rules! reset {
+                            (show_collapsing_imut) => {
+                                ret
+                            };
+                            (show_collapsing) => {
+                                if let (Some(reset2), true) = (_reset2, options.reset_buttons) {
+                                    if !reset2.eguis_eq(self) {
+                                        let mut r = ui.button("⟲");
+                                        if r.clicked() {
+                                            self.eguis_clone(reset2);
+                                            r.mark_changed();
+                                        }
+                                        ret |= r;
                                     }
-                                    ret |= r;
                                 }
-                            }
-                            ret
-                        };
-                    }
-                    reset! {$collapsing_name}
+                                ret
+                            };
+                        }
+                        reset! {$collapsing_name}
+                    })
+                    .inner
                 })
                 .inner;
+            if changed {
+                ret.mark_changed();
+            }
+            if let Some(background) = background {
+                let color = if error.is_some() {
+                    ui.visuals().error_fg_color
+                } else {
+                    ui.visuals().warn_fg_color
+                }
+                .linear_multiply(0.15);
+                paint_row_background(ui, background, label_rect.union(ret.rect), color);
+            }
+            if ret.changed() {
+                track_change(ctx);
+            }
             ui.end_row();
 
-            if has_childs && !collapsed {
-                ret = self.$childs_name(ui, indent_level + 1, ret, _reset2, id);
+            if has_childs && (!collapsed || filter.active || command == Some(true)) {
+                let ctx = ctx.subtree(id, filter, collapsed, command);
+                ret = self.$childs_name(ui, &ctx, indent_level + 1, ret, _reset2, left, id);
             }
             ret
         }
@@ -152,23 +337,28 @@ macro_rules! generate_show {
         /// Do not overide this method.
         ///
         /// Use it when implementing [.show_childs()](EguiStruct::show_childs) to display single nested element
+        #[allow(clippy::too_many_arguments)]
         fn $collapsing_name(
             self: $typ,
             ui: &mut Ui,
+            ctx: &ViewContext,
             label: impl Into<WidgetText> + Clone,
             hint: impl Into<WidgetText> + Clone,
             indent_level: isize,
             config: Self::$config<'_>,
             reset2: Option<&Self>,
+            left: Option<&Self>,
             parent_id: Id,
         ) -> Response {
             self.$show_collapsing_inner(
                 ui,
+                ctx,
                 label,
                 hint,
                 indent_level,
                 config,
                 reset2,
+                left,
                 parent_id,
                 None,
             )
@@ -192,9 +382,11 @@ macro_rules! generate_show {
         fn $childs_name(
             self: $typ,
             _ui: &mut Ui,
+            _ctx: &ViewContext,
             _indent_level: isize,
             _response: Response,
             _reset2: Option<&Self>,
+            _left: Option<&Self>,
             _parent_id: Id,
         ) -> Response {
             unreachable!()
@@ -206,6 +398,13 @@ macro_rules! generate_show {
         fn $start_collapsed(&self) -> bool {
             false
         }
+
+        /// Hint shown on hover of label, if field does not have own hint
+        ///
+        /// Derive macro sets it from struct/enum doc comment (or `hint` attribute)
+        fn $type_hint(&self) -> String {
+            String::new()
+        }
     };
 }
 /// Similar to std [`Clone`] trait, but they respect `#[eguis(skip)]`.
@@ -253,35 +452,1456 @@ macro_rules! impl_eeq {
     };
 }
 
-#[macro_export]
-/// Wrapper for both [impl_eeq!] & [impl_eclone!]
-///
-/// Generate [EguiStructClone] & [EguiStructEq] implementation based on [Clone] & [PartialEq]
-///
-/// Usage:
-/// ```
-/// impl_eeqclone!(MyType)
-/// impl_eeqclone!([T], MyType2) //for MyType2<T>
-/// ```
-macro_rules! impl_eeqclone {
-    ([$($generics:tt)*], $type:ty) => {
-        impl_eeq!{[$($generics)*], $type}
-        impl_eclone!{[$($generics)*], $type}
-    };
-    ($type:ty) => {impl_eeqclone!{[],$type}}
+#[macro_export]
+/// Wrapper for both [impl_eeq!] & [impl_eclone!]
+///
+/// Generate [EguiStructClone] & [EguiStructEq] implementation based on [Clone] & [PartialEq]
+///
+/// Usage:
+/// ```
+/// impl_eeqclone!(MyType)
+/// impl_eeqclone!([T], MyType2) //for MyType2<T>
+/// ```
+macro_rules! impl_eeqclone {
+    ([$($generics:tt)*], $type:ty) => {
+        impl_eeq!{[$($generics)*], $type}
+        impl_eclone!{[$($generics)*], $type}
+    };
+    ($type:ty) => {impl_eeqclone!{[],$type}}
+}
+
+/// Trait, that allows generating mutable view of data (takes `&mut data`)
+///
+///  For end user (if you implement trait with macro & not manualy) ofers one function [`.show_top()`](Self::show_top), which displays struct inside scroll area.
+pub trait EguiStruct: EguiStructClone + EguiStructEq {
+    generate_show! { show_top, show_top_view, show_collapsing, show_collapsing_inner, show_collapsing_widget, show_primitive, show_childs, start_collapsed, type_hint,
+    &mut Self, ConfigType, COLUMN_COUNT, SIMPLE, has_childs, has_primitive, value_text, copy_text }
+
+    /// Check data (& all nested fields) with validators (see `validate` attribute of derive macro)
+    ///
+    /// Returns path of each invalid field with error message (empty if data is valid)
+    fn validate_all(&self) -> Vec<(FieldPath, String)> {
+        Vec::new()
+    }
+
+    /// Check data with struct/enum level validator only (nested fields are not checked; see `validate` attribute of derive macro)
+    ///
+    /// Row of invalid data is highlighted
+    fn validate_self(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Set data from text (copied with "Copy value", see [.copy_text()](Self::copy_text)); used by "Paste value" in row context menu
+    ///
+    /// Returns false if text is invalid (or pasting is not supported)
+    fn paste_text(&mut self, _text: &str) -> bool {
+        false
+    }
+
+    /// Value used by "Reset to default" in row context menu (`None` if not supported)
+    fn default_value(&self) -> Option<Box<Self>> {
+        None
+    }
+
+    /// Same as [`.show_top()`](Self::show_top), but additionaly returns paths of all fields changed in this frame
+    fn show_top_tracked(
+        &mut self,
+        ui: &mut Ui,
+        label: impl Into<WidgetText> + Clone,
+        reset2: Option<&Self>,
+    ) -> (Response, Vec<FieldPath>)
+    where
+        Self: 'static,
+    {
+        let state = ViewState {
+            changed: Some(RefCell::default()),
+            ..Default::default()
+        };
+        let response = self.show_top_view(ui, EguiStructView::new(label), reset2, None, &state);
+        (response, state.changed.unwrap_or_default().into_inner())
+    }
+
+    /// Same as [`.show_top()`](Self::show_top), but with undo/redo buttons (& `Ctrl+Z`/`Ctrl+Y` shortcuts) above data
+    ///
+    /// `history` should be kept between frames (eg. next to data)
+    fn show_top_with_history(
+        &mut self,
+        ui: &mut Ui,
+        label: impl Into<WidgetText> + Clone,
+        reset2: Option<&Self>,
+        history: &mut History<Self>,
+    ) -> Response
+    where
+        Self: Clone + 'static,
+    {
+        let undo_shortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+        let redo_shortcuts = [
+            KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z),
+            KeyboardShortcut::new(Modifiers::COMMAND, Key::Y),
+        ];
+        //focused text edit handles shortcuts itself (undo of its text)
+        let text_focused = ui.ctx().wants_keyboard_input();
+        let (mut undo, mut redo) = ui.input_mut(|i| {
+            if text_focused {
+                return (false, false);
+            }
+            let redo = redo_shortcuts.iter().any(|s| i.consume_shortcut(s));
+            (!redo && i.consume_shortcut(&undo_shortcut), redo)
+        });
+        ui.horizontal(|ui| {
+            let hint = ui.ctx().format_shortcut(&undo_shortcut);
+            undo |= ui
+                .add_enabled(history.can_undo(), Button::new("⟲"))
+                .on_hover_text(hint)
+                .clicked();
+            let hint = ui.ctx().format_shortcut(&redo_shortcuts[1]);
+            redo |= ui
+                .add_enabled(history.can_redo(), Button::new("⟳"))
+                .on_hover_text(hint)
+                .clicked();
+        });
+
+        history.begin(self);
+        let changed = (undo && history.undo(self)) || (redo && history.redo(self));
+        let mut response = self.show_top(ui, label, reset2);
+        //drag gestures are commited as single change, when pointer is released
+        let dragging = ui.input(|i| i.pointer.any_down());
+        history.update(self, response.changed(), dragging);
+        //undo/redo itself is not stored as new change (it would clear redo steps)
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+/// Trait, that allows generating immutable view of data (takes `&data`)
+pub trait EguiStructImut {
+    generate_show! { show_top_imut, show_top_view_imut, show_collapsing_imut, show_collapsing_inner_imut, show_collapsing_widget_imut, show_primitive_imut, show_childs_imut, start_collapsed_imut, type_hint_imut,
+    &Self, ConfigTypeImut, COLUMN_COUNT_IMUT, SIMPLE_IMUT, has_childs_imut, has_primitive_imut, value_text_imut, copy_text_imut }
+}
+
+/// Builder that shows data like [`.show_top()`](EguiStruct::show_top), but with configurable layout
+///
+/// Eg. to embed data inside existing ScrollArea/side panel/window:
+/// ```ignore
+/// EguiStructView::new("Data")
+///     .scroll(false)
+///     .striped(true)
+///     .show(ui, &mut data, None);
+/// ```
+pub struct EguiStructView {
+    label: WidgetText,
+    scroll: bool,
+    max_height: Option<f32>,
+    striped: Option<bool>,
+    num_columns: Option<usize>,
+    spacing: Option<egui::Vec2>,
+    min_col_width: Option<f32>,
+    options: ShowOptions,
+}
+
+impl EguiStructView {
+    /// `label` is shown in top row (& is used as id source)
+    pub fn new(label: impl Into<WidgetText>) -> Self {
+        Self {
+            label: label.into(),
+            scroll: true,
+            max_height: None,
+            striped: None,
+            num_columns: None,
+            spacing: None,
+            min_col_width: None,
+            options: ShowOptions::default(),
+        }
+    }
+    /// Options passed to all rows of view (read-only mode, collapse depth, hints, ..)
+    pub fn options(mut self, options: ShowOptions) -> Self {
+        self.options = options;
+        self
+    }
+    /// Show search filter box above data (default: false; same as [ShowOptions::search])
+    pub fn search(mut self, search: bool) -> Self {
+        self.options.search = search;
+        self
+    }
+    /// Put grid inside vertical ScrollArea (default: true); disable when view is already inside ScrollArea
+    pub fn scroll(mut self, scroll: bool) -> Self {
+        self.scroll = scroll;
+        self
+    }
+    /// Maximum height of ScrollArea
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+    /// Stripe rows of grid (default: as set in `style.visuals.striped`)
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = Some(striped);
+        self
+    }
+    /// Number of grid columns (used for striping & column widths; default: 2)
+    pub fn num_columns(mut self, num_columns: usize) -> Self {
+        self.num_columns = Some(num_columns);
+        self
+    }
+    /// Spacing between grid columns & rows
+    pub fn spacing(mut self, spacing: impl Into<egui::Vec2>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+    /// Minimum width of each grid column
+    pub fn min_col_width(mut self, min_col_width: f32) -> Self {
+        self.min_col_width = Some(min_col_width);
+        self
+    }
+
+    /// Show mutable view of `data` (see [`.show_top()`](EguiStruct::show_top))
+    pub fn show<T: EguiStruct + ?Sized + 'static>(
+        self,
+        ui: &mut Ui,
+        data: &mut T,
+        reset2: Option<&T>,
+    ) -> Response {
+        data.show_top_view(ui, self, reset2, None, &ViewState::default())
+    }
+    /// Show imutable view of `data` (see [`.show_top_imut()`](EguiStructImut::show_top_imut))
+    pub fn show_imut<T: EguiStructImut + ?Sized + 'static>(
+        self,
+        ui: &mut Ui,
+        data: &T,
+        reset2: Option<&T>,
+    ) -> Response {
+        data.show_top_view_imut(ui, self, reset2, None, &ViewState::default())
+    }
+
+    fn scroll_area<R>(&self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+        if !self.scroll {
+            return add_contents(ui);
+        }
+        let mut scroll = ScrollArea::vertical();
+        if let Some(max_height) = self.max_height {
+            scroll = scroll.max_height(max_height);
+        }
+        scroll.show(ui, add_contents).inner
+    }
+    fn grid(&self, id: Id, num_columns: usize) -> Grid {
+        let mut grid = Grid::new(id).num_columns(self.num_columns.unwrap_or(num_columns));
+        if let Some(striped) = self.striped {
+            grid = grid.striped(striped);
+        }
+        if let Some(spacing) = self.spacing {
+            grid = grid.spacing(spacing);
+        }
+        if let Some(min_col_width) = self.min_col_width {
+            grid = grid.min_col_width(min_col_width);
+        }
+        grid
+    }
+}
+
+/// How hint (doc comment or `hint` attribute) of row is shown
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HintMode {
+    /// On hover of label
+    #[default]
+    Hover,
+    /// Next to label (as small text)
+    Inline,
+    /// Hints are not shown
+    Hidden,
+}
+
+/// How nested rows are indented
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum IndentStyle {
+    /// Vertical separator for each level
+    #[default]
+    Separator,
+    /// Empty space (of given width) for each level
+    Space(f32),
+}
+
+/// Options of [EguiStructView], that are available to all its rows (see [ViewContext::options])
+///
+/// ```ignore
+/// EguiStructView::new("Data")
+///     .options(ShowOptions::default().read_only(true).collapse_depth(1))
+///     .show(ui, &mut data, None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShowOptions {
+    /// Mutable view is shown as disabled (values can not be edited)
+    pub read_only: bool,
+    /// Rows nested deeper than this start collapsed (`0`: only top level fields are shown); field `start_collapsed` attribute takes precedence
+    pub collapse_depth: Option<usize>,
+    /// Show reset (⟲) buttons next to values, that differ from `reset2` (reset is still available in row context menu)
+    pub reset_buttons: bool,
+    /// How hints of rows are shown (default: on hover of label)
+    pub hint_mode: HintMode,
+    /// How nested rows are indented (default: separator for each level)
+    pub indent_style: IndentStyle,
+    /// Show search filter box above data (default: false): while it is not empty, only rows, which label/hint (or optionally value) contains text, are shown (with their ancestors & childs)
+    pub search: bool,
+    /// Keep collapse state of rows in egui persisted memory (`insert_persisted`), so it survives app restarts (default: false)
+    ///
+    /// Requires egui `persistence` feature (eg. eframe with `persistence` feature & `App::persist_egui_memory`)
+    pub persist_collapsed: bool,
+    /// Id source of view (instead of label; keeps collapse/filter state when label changes)
+    pub id_salt: Option<Id>,
+    /// Minimum width of label column
+    pub label_width: Option<f32>,
+    /// Minimum width of value column(s)
+    pub value_width: Option<f32>,
+}
+
+impl Default for ShowOptions {
+    fn default() -> Self {
+        Self {
+            read_only: false,
+            collapse_depth: None,
+            reset_buttons: true,
+            hint_mode: HintMode::default(),
+            indent_style: IndentStyle::default(),
+            search: false,
+            persist_collapsed: false,
+            id_salt: None,
+            label_width: None,
+            value_width: None,
+        }
+    }
+}
+
+impl ShowOptions {
+    /// Show mutable view as disabled (default: false)
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+    /// Rows nested deeper than `collapse_depth` start collapsed
+    pub fn collapse_depth(mut self, collapse_depth: usize) -> Self {
+        self.collapse_depth = Some(collapse_depth);
+        self
+    }
+    /// Show reset (⟲) buttons next to changed values (default: true)
+    pub fn reset_buttons(mut self, reset_buttons: bool) -> Self {
+        self.reset_buttons = reset_buttons;
+        self
+    }
+    /// How hints of rows are shown (default: [HintMode::Hover])
+    pub fn hint_mode(mut self, hint_mode: HintMode) -> Self {
+        self.hint_mode = hint_mode;
+        self
+    }
+    /// How nested rows are indented (default: [IndentStyle::Separator])
+    pub fn indent_style(mut self, indent_style: IndentStyle) -> Self {
+        self.indent_style = indent_style;
+        self
+    }
+    /// Show search filter box above data (default: false; see [ShowOptions::search])
+    pub fn search(mut self, search: bool) -> Self {
+        self.search = search;
+        self
+    }
+    /// Keep collapse state of rows in egui persisted memory (default: false; see [ShowOptions::persist_collapsed])
+    pub fn persist_collapsed(mut self, persist_collapsed: bool) -> Self {
+        self.persist_collapsed = persist_collapsed;
+        self
+    }
+    /// Id source of view (instead of label)
+    pub fn id_salt(mut self, id_salt: impl Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+    /// Minimum widths of label & value columns
+    pub fn column_widths(mut self, label_width: f32, value_width: f32) -> Self {
+        self.label_width = Some(label_width);
+        self.value_width = Some(value_width);
+        self
+    }
+
+    fn collapsed_at(&self, indent_level: isize) -> bool {
+        self.collapse_depth
+            .is_some_and(|depth| indent_level >= depth as isize)
+    }
+}
+
+/// Context of rows of single view, passed to [`.show_childs()`](EguiStruct::show_childs)/[`.show_collapsing()`](EguiStruct::show_collapsing)
+///
+/// Custom [`.show_childs()`](EguiStruct::show_childs) implementations should pass it to nested rows
+pub struct ViewContext<'a> {
+    options: &'a ShowOptions,
+    state: &'a ViewState,
+    ///Context of parent row
+    parent: Option<&'a ViewContext<'a>>,
+    ///Field (or variant/element) entered by this context (converted to [FieldPath] only when needed)
+    segment: Option<PathSegment<'a>>,
+    ///Row (& its search filter state), which childs are shown with this context
+    filter_row: Option<(Id, FilterRow)>,
+    ///Rows are visited (eg. to find search filter matches), but not shown
+    hidden: bool,
+    ///Rows can be edited (false in read-only view & for fields disabled with `enabled_if`)
+    enabled: bool,
+    ///"Expand all"/"collapse all" is applied to ancestor row
+    collapse_all: Option<bool>,
+    ///Validation error of row shown with this context (not inherited by nested rows)
+    error: Option<String>,
+}
+
+impl<'a> ViewContext<'a> {
+    /// Context of top level row (used by [EguiStructView]; needed only if rows are shown outside of it)
+    pub fn new(options: &'a ShowOptions, state: &'a ViewState) -> Self {
+        Self {
+            options,
+            state,
+            parent: None,
+            segment: None,
+            filter_row: None,
+            hidden: false,
+            enabled: !options.read_only,
+            collapse_all: None,
+            error: None,
+        }
+    }
+    /// Options of view
+    pub fn options(&self) -> &'a ShowOptions {
+        self.options
+    }
+    /// Rows can be edited (view is not read-only & rows are not disabled by `enabled_if` attribute of derive macro)
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Path of currently shown field (eg. for "Copy field path" in row context menu)
+    pub fn path(&self) -> FieldPath {
+        let mut path: Vec<_> = std::iter::successors(Some(self), |ctx| ctx.parent)
+            .filter_map(|ctx| ctx.segment.map(PathSegment::to_segment))
+            .collect();
+        path.reverse();
+        FieldPath(path)
+    }
+    /// Context of nested field `name` (of struct/enum variant)
+    pub fn with_field(&self, name: &'static str) -> ViewContext<'_> {
+        self.with_segment(PathSegment::Field(name))
+    }
+    /// Context of fields of enum variant `name`
+    pub fn with_variant(&self, name: &'static str) -> ViewContext<'_> {
+        self.with_segment(PathSegment::Variant(name))
+    }
+    /// Context of element `idx` of list-like collection
+    pub fn with_index(&self, idx: usize) -> ViewContext<'_> {
+        self.with_segment(PathSegment::Index(idx))
+    }
+    /// Context of value of map-like collection stored under `key`
+    pub fn with_key<'b>(&'b self, key: &'b dyn ToString) -> ViewContext<'b> {
+        self.with_segment(PathSegment::Key(key))
+    }
+
+    #[doc(hidden)]
+    ///Context of rows, that are shown as disabled if `enabled` is false (see `enabled_if` attribute of derive macro)
+    pub fn with_enabled(&self, enabled: bool) -> ViewContext<'_> {
+        ViewContext {
+            enabled: self.enabled && enabled,
+            ..self.same_row()
+        }
+    }
+
+    #[doc(hidden)]
+    ///Context of row, that is highlighted as invalid if `error` is set (see `validate` attribute of derive macro)
+    pub fn with_error(&self, error: Option<String>) -> ViewContext<'_> {
+        ViewContext {
+            error,
+            ..self.same_row()
+        }
+    }
+
+    ///Id of row shown with this context: derived from field (or element/key) it shows, so it does not change with (translated) label; `label` is used only for rows without field
+    fn row_id(&self, parent_id: Id, label: impl FnOnce() -> String) -> Id {
+        match self.segment {
+            Some(segment) => parent_id.with(segment.to_segment()),
+            None => parent_id.with(label()),
+        }
+    }
+
+    ///Copy of this context (with the same parent & field), used to change its flags
+    fn same_row(&self) -> ViewContext<'_> {
+        ViewContext {
+            options: self.options,
+            state: self.state,
+            parent: self.parent,
+            segment: self.segment,
+            filter_row: self.filter_row.clone(),
+            hidden: self.hidden,
+            enabled: self.enabled,
+            collapse_all: self.collapse_all,
+            error: self.error.clone(),
+        }
+    }
+
+    fn with_segment<'b>(&'b self, segment: PathSegment<'b>) -> ViewContext<'b> {
+        ViewContext {
+            segment: Some(segment),
+            ..self.child()
+        }
+    }
+
+    ///Context of nested rows, that inherits state of this context
+    fn child(&self) -> ViewContext<'_> {
+        ViewContext {
+            options: self.options,
+            state: self.state,
+            parent: Some(self),
+            segment: None,
+            filter_row: None,
+            hidden: self.hidden,
+            enabled: self.enabled,
+            collapse_all: self.collapse_all,
+            error: None,
+        }
+    }
+
+    ///Context of childs of row `id` (`collapsed` childs are visited, but not shown)
+    fn subtree(
+        &self,
+        id: Id,
+        mut filter: FilterRow,
+        collapsed: bool,
+        command: Option<bool>,
+    ) -> ViewContext<'_> {
+        filter.hide_childs = collapsed;
+        ViewContext {
+            filter_row: filter.active.then_some((id, filter)),
+            hidden: self.hidden || collapsed,
+            collapse_all: command,
+            ..self.child()
+        }
+    }
+
+    ///Search filter state of ancestor rows (starting from parent)
+    fn filter_ancestors(&self) -> impl Iterator<Item = &(Id, FilterRow)> {
+        std::iter::successors(Some(self), |ctx| ctx.parent)
+            .filter_map(|ctx| ctx.filter_row.as_ref())
+    }
+}
+
+///Segment of [FieldPath] borrowed from shown data
+#[derive(Clone, Copy)]
+enum PathSegment<'a> {
+    Field(&'static str),
+    Variant(&'static str),
+    Index(usize),
+    Key(&'a dyn ToString),
+}
+
+impl PathSegment<'_> {
+    fn to_segment(self) -> FieldPathSegment {
+        match self {
+            PathSegment::Field(name) => FieldPathSegment::Field(name.to_string()),
+            PathSegment::Variant(name) => FieldPathSegment::Variant(name.to_string()),
+            PathSegment::Index(idx) => FieldPathSegment::Index(idx),
+            PathSegment::Key(key) => FieldPathSegment::Key(key.to_string()),
+        }
+    }
+}
+
+/// State shared by all rows of single view (eg. search filter); new state is created for each shown view
+#[derive(Default)]
+pub struct ViewState {
+    filter: RefCell<Option<SearchFilter>>,
+    ///Paths of fields changed in current frame (if tracked by [`show_top_tracked`](EguiStruct::show_top_tracked))
+    changed: Option<RefCell<Vec<FieldPath>>>,
+    ///`Some(hide_equal)` if view is shown by [show_diff]
+    diff: Option<bool>,
+}
+
+fn show_indent(ui: &mut Ui, indent_level: isize, style: IndentStyle) {
+    for _ in 0..indent_level {
+        match style {
+            IndentStyle::Separator => {
+                ui.separator();
+            }
+            IndentStyle::Space(width) => ui.add_space(width),
+        }
+    }
+}
+
+///Collapse/uncollapse button (state is kept in temp data of `id`); returns true if collapsed
+///
+/// If `force_expand` is set (eg. by search filter), row is shown as expanded regardless of state
+fn show_collapse_toggle(
+    ui: &mut Ui,
+    options: &ShowOptions,
+    id: Id,
+    start_collapsed: impl FnOnce() -> bool,
+    force_expand: bool,
+) -> bool {
+    let collapsed = collapsed_state(ui, options, id, start_collapsed);
+    if force_expand {
+        Button::new("⏷").frame(false).small().ui(ui);
+        return false;
+    }
+    let icon = if collapsed { "⏵" } else { "⏷" };
+    if Button::new(icon).frame(false).small().ui(ui).clicked() {
+        set_collapsed(ui, options, id, !collapsed);
+    }
+    collapsed
+}
+
+///Label cell of row: indent, collapse toggle (if row is collapsible: `start_collapsed` is `Some`), label with hint, validation `error` (shown on hover of ⚠) & context menu (with entries added by `menu`)
+///
+///Returns rect of cell & true if row is collapsed
+#[allow(clippy::too_many_arguments)]
+fn show_row_label(
+    ui: &mut Ui,
+    ctx: &ViewContext,
+    id: Id,
+    indent_level: isize,
+    mut label: WidgetText,
+    hint: WidgetText,
+    filter: &FilterRow,
+    start_collapsed: Option<bool>,
+    error: Option<&str>,
+    menu: impl FnOnce(&mut Ui),
+) -> (egui::Rect, bool) {
+    let options = ctx.options();
+    let enabled = ctx.enabled();
+    if let Some(query) = &filter.highlight {
+        label = highlight_text(ui, label, query);
+    }
+    let mut collapsed = false;
+    let rect = ui
+        .horizontal(|ui| {
+            if let Some(width) = options.label_width {
+                ui.set_min_width(width);
+            }
+            if indent_level >= 0 {
+                show_indent(ui, indent_level, options.indent_style);
+                if let Some(start_collapsed) = start_collapsed {
+                    collapsed =
+                        show_collapse_toggle(ui, options, id, || start_collapsed, filter.expand);
+                }
+            }
+            let mut lab = ui.add_enabled(enabled, Label::new(label));
+            match options.hint_mode {
+                HintMode::Hover if !hint.is_empty() => {
+                    lab = if enabled {
+                        lab.on_hover_text(hint)
+                    } else {
+                        lab.on_disabled_hover_text(hint)
+                    };
+                }
+                HintMode::Inline if !hint.is_empty() => {
+                    let hint = egui::RichText::new(hint.text()).small().weak();
+                    ui.add_enabled(enabled, Label::new(hint));
+                }
+                _ => (),
+            }
+            if let Some(error) = error {
+                let color = ui.visuals().error_fg_color;
+                ui.colored_label(color, "⚠").on_hover_text(error);
+            }
+            let _ = lab.context_menu(|ui| {
+                if start_collapsed.is_some() {
+                    show_collapse_menu(ui, id);
+                    ui.separator();
+                }
+                menu(ui);
+                show_path_menu(ui, ctx);
+            });
+            if start_collapsed.is_some() && indent_level < 0 {
+                show_collapse_all_buttons(ui, id);
+            }
+        })
+        .response
+        .rect;
+    (rect, collapsed)
+}
+
+/// Show two values side by side in three-column grid (label | left | right)
+///
+/// Rows where values differ (according to [EguiStructEq]) are highlighted & have button that copies left value to right (with [EguiStructClone]).
+/// Above grid "Hide equal rows" toggle is shown. `right` can be also edited directly (& reset to `reset2`, like in [`.show_top()`](EguiStruct::show_top)).
+pub fn show_diff<T: EguiStruct + 'static>(
+    ui: &mut Ui,
+    label: impl Into<WidgetText> + Clone,
+    left: &T,
+    right: &mut T,
+    reset2: Option<&T>,
+) -> Response {
+    let label: WidgetText = label.into();
+    let hide_id = ui
+        .make_persistent_id((label.text().to_string(), std::any::TypeId::of::<T>()))
+        .with("__EguiStruct_diff_hide_equal");
+    let mut hide_equal = ui.data_mut(|d| d.get_temp(hide_id).unwrap_or(false));
+    let text = translate(ui, "egui_struct.hide_equal_rows", "Hide equal rows");
+    if ui.checkbox(&mut hide_equal, text).changed() {
+        ui.data_mut(|d| d.insert_temp(hide_id, hide_equal));
+    }
+    let state = ViewState {
+        diff: Some(hide_equal),
+        ..Default::default()
+    };
+    let view = EguiStructView::new(label).num_columns(3);
+    right.show_top_view(ui, view, reset2, Some(left), &state)
+}
+
+///Left value cell of row shown by [show_diff]; returns true if "copy left → right" button (shown if `copy` is set) was clicked
+fn show_diff_cell(
+    ui: &mut Ui,
+    ctx: &ViewContext,
+    left: Option<String>,
+    enabled: bool,
+    copy: bool,
+) -> bool {
+    ui.horizontal(|ui| {
+        if let Some(width) = ctx.options().value_width {
+            ui.set_min_width(width);
+        }
+        ui.add_enabled(enabled, Label::new(left.unwrap_or_default()));
+        copy && ui
+            .small_button("→")
+            .on_hover_text(translate(
+                ui,
+                "egui_struct.copy_left_to_right",
+                "Copy left → right",
+            ))
+            .clicked()
+    })
+    .inner
+}
+
+///Fill `rect` (expanded to cover grid spacing) with `color` behind row (`background` is placeholder added before row)
+fn paint_row_background(
+    ui: &Ui,
+    background: egui::layers::ShapeIdx,
+    rect: egui::Rect,
+    color: egui::Color32,
+) {
+    let rect = rect.expand2(ui.spacing().item_spacing / 2.0);
+    ui.painter()
+        .set(background, egui::Shape::rect_filled(rect, 0.0, color));
+}
+
+///Id of temp data with last text copied with "Copy value"/"Copy field path"
+const CLIPBOARD_ID: &str = "__EguiStruct_clipboard";
+
+fn copy_to_clipboard(ui: &mut Ui, text: String) {
+    ui.data_mut(|d| d.insert_temp(Id::new(CLIPBOARD_ID), text.clone()));
+    ui.output_mut(|o| o.copied_text = text);
+    ui.close_menu();
+}
+
+///"Copy value" entry of row context menu
+fn show_copy_menu(ui: &mut Ui, text: Option<String>) {
+    if let Some(text) = text {
+        if ui
+            .button(translate(ui, "egui_struct.copy_value", "Copy value"))
+            .clicked()
+        {
+            copy_to_clipboard(ui, text);
+        }
+    } else {
+        let text = translate(ui, "egui_struct.copy_value", "Copy value");
+        ui.add_enabled(false, Button::new(text));
+    }
+}
+
+///"Paste value" & reset entries of row context menu; returns true if value was changed
+fn show_edit_menu<T: EguiStruct + ?Sized>(
+    ui: &mut Ui,
+    id: Id,
+    value: &mut T,
+    reset2: Option<&T>,
+) -> bool {
+    let mut changed = false;
+    let text = translate(ui, "egui_struct.paste_value", "Paste value");
+    ui.menu_button(text, |ui| {
+        //text pasted from other app (with Ctrl+V) is edited here; initially contains last copied text
+        let text_id = id.with("__EguiStruct_paste");
+        let mut text = ui.data_mut(|d| {
+            let copied = d.get_temp::<String>(Id::new(CLIPBOARD_ID));
+            d.get_temp_mut_or_insert_with(text_id, || copied.unwrap_or_default())
+                .clone()
+        });
+        let edit = ui.add(egui::TextEdit::singleline(&mut text).hint_text("Ctrl+V"));
+        let submit = edit.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+        let invalid_id = text_id.with("invalid");
+        if ui
+            .button(translate(ui, "egui_struct.paste", "Paste"))
+            .clicked()
+            || submit
+        {
+            if value.paste_text(&text) {
+                changed = true;
+                ui.data_mut(|d| d.remove::<String>(text_id));
+                ui.close_menu();
+                return;
+            }
+            ui.data_mut(|d| d.insert_temp(invalid_id, true));
+        } else if edit.changed() {
+            ui.data_mut(|d| d.insert_temp(invalid_id, false));
+        }
+        if ui.data_mut(|d| d.get_temp(invalid_id).unwrap_or(false)) {
+            let text = translate(ui, "egui_struct.invalid_value", "Invalid value");
+            ui.colored_label(ui.visuals().error_fg_color, text);
+        }
+        ui.data_mut(|d| d.insert_temp(text_id, text));
+    });
+    let reset = reset2.filter(|r| !r.eguis_eq(value));
+    let text = translate(ui, "egui_struct.reset", "Reset");
+    if ui.add_enabled(reset.is_some(), Button::new(text)).clicked() {
+        if let Some(reset) = reset {
+            value.eguis_clone(reset);
+            changed = true;
+        }
+        ui.close_menu();
+    }
+    let default = value.default_value().filter(|d| !d.eguis_eq(value));
+    let text = translate(ui, "egui_struct.reset_to_default", "Reset to default");
+    if ui
+        .add_enabled(default.is_some(), Button::new(text))
+        .clicked()
+    {
+        if let Some(default) = default {
+            value.eguis_clone(&default);
+            changed = true;
+        }
+        ui.close_menu();
+    }
+    changed
+}
+
+///"Copy field path" entry of row context menu
+fn show_path_menu(ui: &mut Ui, ctx: &ViewContext) {
+    let path = ctx.path();
+    if !path.0.is_empty() {
+        ui.separator();
+        let text = translate(ui, "egui_struct.copy_field_path", "Copy field path");
+        if ui.button(text).clicked() {
+            copy_to_clipboard(ui, path.to_string());
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+/// Text representation (RON) of value; used by derive macro for types with `clipboard` attribute
+pub fn serde_copy_text<T: serde::Serialize + ?Sized>(value: &T) -> Option<String> {
+    ron::to_string(value).ok()
+}
+
+#[cfg(feature = "serde")]
+/// Set value from text produced by [serde_copy_text] (fields marked with `#[eguis(skip)]` are not changed)
+pub fn serde_paste_text<T: serde::de::DeserializeOwned + EguiStructClone>(
+    value: &mut T,
+    text: &str,
+) -> bool {
+    ron::from_str::<T>(text.trim())
+        .map(|v| value.eguis_clone(&v))
+        .is_ok()
+}
+
+///Id of temp data with function, that translates texts shown by egui_struct (see [set_translator])
+const TRANSLATOR_ID: &str = "__EguiStruct_translator";
+
+/// Translate texts shown by egui_struct itself (not labels of fields) with `translator` (eg. `|key| rust_i18n::t!(key).to_string()`)
+///
+/// `translator` gets key of text & should return translated text; used keys (with english text shown by default):
+/// - `egui_struct.hide_equal_rows`: "Hide equal rows"
+/// - `egui_struct.copy_left_to_right`: "Copy left → right"
+/// - `egui_struct.selected`: "%{count} selected" (`%{count}` is replaced with number of selected values)
+/// - `egui_struct.copy_value`: "Copy value"
+/// - `egui_struct.paste_value`: "Paste value"
+/// - `egui_struct.paste`: "Paste"
+/// - `egui_struct.invalid_value`: "Invalid value"
+/// - `egui_struct.reset`: "Reset"
+/// - `egui_struct.reset_to_default`: "Reset to default"
+/// - `egui_struct.copy_field_path`: "Copy field path"
+/// - `egui_struct.expand_all`: "Expand all"
+/// - `egui_struct.collapse_all`: "Collapse all"
+/// - `egui_struct.match_values`: "Match values"
+/// - `egui_struct.apply`: "Apply"
+/// - `egui_struct.cancel`: "Cancel"
+/// - `egui_struct.reset_all`: "Reset all"
+pub fn set_translator(ctx: &egui::Context, translator: fn(&str) -> String) {
+    ctx.data_mut(|d| d.insert_temp(Id::new(TRANSLATOR_ID), translator));
+}
+
+///Text `key` translated with function set by [set_translator] (or `text` if it is not set)
+fn translate(ui: &Ui, key: &str, text: &str) -> String {
+    match ui.data_mut(|d| d.get_temp::<fn(&str) -> String>(Id::new(TRANSLATOR_ID))) {
+        Some(translator) => translator(key),
+        None => text.to_string(),
+    }
+}
+
+fn collapsed_state(
+    ui: &Ui,
+    options: &ShowOptions,
+    id: Id,
+    start_collapsed: impl FnOnce() -> bool,
+) -> bool {
+    let id = id.with("__EguiStruct_collapsing_state");
+    ui.data_mut(|d| {
+        if options.persist_collapsed {
+            *d.get_persisted_mut_or_insert_with(id, start_collapsed)
+        } else {
+            *d.get_temp_mut_or_insert_with(id, start_collapsed)
+        }
+    })
+}
+
+fn set_collapsed(ui: &Ui, options: &ShowOptions, id: Id, collapsed: bool) {
+    let id = id.with("__EguiStruct_collapsing_state");
+    ui.data_mut(|d| {
+        if options.persist_collapsed {
+            d.insert_persisted(id, collapsed)
+        } else {
+            d.insert_temp(id, collapsed)
+        }
+    })
+}
+
+///Returns `Some(collapse)` if "expand all"/"collapse all" is applied to row `id` (or its ancestor)
+fn collapse_all_command(ui: &Ui, ctx: &ViewContext, id: Id) -> Option<bool> {
+    let request_id = id.with("__EguiStruct_collapse_all");
+    let requested = ui.data_mut(|d| {
+        let requested = d.get_temp::<bool>(request_id);
+        if requested.is_some() {
+            d.remove::<bool>(request_id);
+        }
+        requested
+    });
+    requested.or(ctx.collapse_all)
+}
+
+///Expand/collapse row `id` & all its descendants (in next frame)
+fn request_collapse_all(ui: &Ui, id: Id, collapse: bool) {
+    ui.data_mut(|d| d.insert_temp(id.with("__EguiStruct_collapse_all"), collapse));
+    ui.ctx().request_repaint();
+}
+
+///Context menu of collapsible row
+fn show_collapse_menu(ui: &mut Ui, id: Id) {
+    if ui
+        .button(translate(ui, "egui_struct.expand_all", "Expand all"))
+        .clicked()
+    {
+        request_collapse_all(ui, id, false);
+        ui.close_menu();
+    }
+    if ui
+        .button(translate(ui, "egui_struct.collapse_all", "Collapse all"))
+        .clicked()
+    {
+        request_collapse_all(ui, id, true);
+        ui.close_menu();
+    }
+}
+
+///"Expand all"/"collapse all" buttons (shown in top row)
+fn show_collapse_all_buttons(ui: &mut Ui, id: Id) {
+    let button = |ui: &mut Ui, icon| Button::new(icon).frame(false).small().ui(ui);
+    let text = translate(ui, "egui_struct.expand_all", "Expand all");
+    if button(ui, "➕").on_hover_text(text).clicked() {
+        request_collapse_all(ui, id, false);
+    }
+    let text = translate(ui, "egui_struct.collapse_all", "Collapse all");
+    if button(ui, "➖").on_hover_text(text).clicked() {
+        request_collapse_all(ui, id, true);
+    }
+}
+
+#[doc(hidden)]
+///Type of field of enum variant, that is created with `Default` when variant is selected in combobox
+///
+///If field type does not implement `Default`, set `default`/`constructor` attribute on the variant
+#[cfg_attr(
+    egui_struct_diagnostic,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` (type of enum variant field) does not implement `Default`, so variant can not be created when it is selected in combobox",
+        label = "`{Self}` does not implement `Default`",
+        note = "add #[eguis(default = expr)] or #[eguis(constructor = path)] to the variant (or implement `Default` for `{Self}`)"
+    )
+)]
+pub trait VariantFieldDefault {
+    fn variant_field_default() -> Self;
+}
+impl<T: Default> VariantFieldDefault for T {
+    fn variant_field_default() -> Self {
+        T::default()
+    }
+}
+
+#[doc(hidden)]
+///Fields group (see `group` attribute of derive macro): header row & (if not collapsed) rows added by `add_rows`
+pub fn show_group(
+    ui: &mut Ui,
+    ctx: &ViewContext,
+    label: impl Into<WidgetText>,
+    indent_level: isize,
+    id: Id,
+    start_collapsed: bool,
+    add_rows: impl FnOnce(&mut Ui, &ViewContext, isize),
+) {
+    let mut label: WidgetText = label.into();
+    let filter = filter_row(
+        ctx,
+        id,
+        indent_level,
+        &label,
+        &WidgetText::default(),
+        || None,
+    );
+    let command = collapse_all_command(ui, ctx, id);
+    if let Some(collapse) = command {
+        set_collapsed(ui, ctx.options(), id, collapse);
+    }
+    let mut collapsed = true;
+    if filter.draw && !ctx.hidden {
+        if let Some(query) = &filter.highlight {
+            label = highlight_text(ui, label, query);
+        }
+        let options = ctx.options();
+        collapsed = ui
+            .horizontal(|ui| {
+                show_indent(ui, indent_level, options.indent_style);
+                let collapsed = show_collapse_toggle(
+                    ui,
+                    options,
+                    id,
+                    || start_collapsed || options.collapsed_at(indent_level),
+                    filter.expand,
+                );
+                let _ = ui
+                    .label(label)
+                    .context_menu(|ui| show_collapse_menu(ui, id));
+                collapsed
+            })
+            .inner;
+        ui.end_row();
+    }
+    if !collapsed || filter.active || command == Some(true) {
+        add_rows(
+            ui,
+            &ctx.subtree(id, filter, collapsed, command),
+            indent_level + 1,
+        );
+    }
+}
+
+///State of search filter (shown above data by [`.show_top()`](EguiStruct::show_top))
+#[derive(Clone, Default)]
+struct SearchFilter {
+    ///Lowercase query
+    query: String,
+    match_values: bool,
+    ///Rows with matching descendant (in previous frame; they are shown & expanded)
+    matched_below: HashSet<Id>,
+    ///Rows with matching descendant (in current frame)
+    next_matched_below: HashSet<Id>,
+}
+
+///How row shall be shown, when search filter is active
+#[derive(Clone, Default)]
+struct FilterRow {
+    ///Search filter is active
+    active: bool,
+    ///Row is shown (it matches query, or its ancestor/descendant does)
+    draw: bool,
+    ///Row is expanded (some of its descendants matches query)
+    expand: bool,
+    ///Row itself matches query
+    matched: bool,
+    ///Query to highlight in label (if label matches)
+    highlight: Option<String>,
+    ///Childs of row are hidden
+    hide_childs: bool,
+}
+
+fn filter_row(
+    ctx: &ViewContext,
+    id: Id,
+    indent_level: isize,
+    label: &WidgetText,
+    hint: &WidgetText,
+    value: impl FnOnce() -> Option<String>,
+) -> FilterRow {
+    let mut row = FilterRow {
+        draw: true,
+        ..Default::default()
+    };
+    let mut filter = ctx.state.filter.borrow_mut();
+    let Some(filter) = filter.as_mut() else {
+        return row;
+    };
+    row.active = true;
+    if indent_level < 0 {
+        row.expand = true;
+        return row;
+    }
+    let matches = |text: &str| text.to_lowercase().contains(&filter.query);
+    let label_matched = matches(label.text());
+    row.matched = label_matched
+        || matches(hint.text())
+        || (filter.match_values && value().is_some_and(|v| matches(&v)));
+    if label_matched {
+        row.highlight = Some(filter.query.clone());
+    }
+    if row.matched {
+        for (ancestor, _) in ctx.filter_ancestors() {
+            filter.next_matched_below.insert(*ancestor);
+        }
+    }
+    row.expand = filter.matched_below.contains(&id);
+    let parent = ctx.filter_ancestors().next().map(|(_, row)| row);
+    let parent_hidden = parent.is_some_and(|p| !p.draw || p.hide_childs);
+    let ancestor_matched = ctx.filter_ancestors().any(|(_, row)| row.matched);
+    row.draw = !parent_hidden && (row.matched || ancestor_matched || row.expand);
+    row
+}
+
+///Search filter text box above `add_contents`; while query is not empty, only matching rows (& their ancestors/descendants) are shown
+fn show_search_filter<R>(
+    ui: &mut Ui,
+    id: Id,
+    state: &ViewState,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> R {
+    let state_id = id.with("__EguiStruct_filter_state");
+    let (mut query, mut match_values, matched_below) = ui.data_mut(|d| {
+        d.get_temp::<(String, bool, HashSet<Id>)>(state_id)
+            .unwrap_or_default()
+    });
+    ui.horizontal(|ui| {
+        egui::TextEdit::singleline(&mut query)
+            .hint_text("🔍")
+            .ui(ui);
+        let text = translate(ui, "egui_struct.match_values", "Match values");
+        ui.toggle_value(&mut match_values, "=").on_hover_text(text);
+    });
+    let filter = (!query.is_empty()).then(|| SearchFilter {
+        query: query.to_lowercase(),
+        match_values,
+        matched_below,
+        ..Default::default()
+    });
+    state.filter.replace(filter);
+    let ret = add_contents(ui);
+    let matched_below = state.filter.take().map_or(HashSet::new(), |f| {
+        if f.next_matched_below != f.matched_below {
+            //rows to show are known only after whole data is visited
+            ui.ctx().request_repaint();
+        }
+        f.next_matched_below
+    });
+    ui.data_mut(|d| d.insert_temp(state_id, (query, match_values, matched_below)));
+    ret
+}
+
+///Highlight (case-insensitive) occurrences of `query` in `text`
+fn highlight_text(ui: &Ui, text: WidgetText, query: &str) -> WidgetText {
+    let plain = text.text().to_string();
+    let lowercase = plain.to_lowercase();
+    if lowercase.len() != plain.len() {
+        //byte offsets of lowercase text does not match original text
+        return text;
+    }
+    let format = egui::TextFormat::simple(
+        egui::TextStyle::Body.resolve(ui.style()),
+        ui.visuals().text_color(),
+    );
+    let highlighted = egui::TextFormat {
+        background: ui.visuals().selection.bg_fill,
+        ..format.clone()
+    };
+    let mut job = egui::text::LayoutJob::default();
+    let mut last = 0;
+    for (start, matched) in lowercase.match_indices(query) {
+        job.append(&plain[last..start], 0.0, format.clone());
+        job.append(
+            &plain[start..start + matched.len()],
+            0.0,
+            highlighted.clone(),
+        );
+        last = start + matched.len();
+    }
+    job.append(&plain[last..], 0.0, format);
+    job.into()
+}
+
+/// Single element of [FieldPath]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FieldPathSegment {
+    /// Named (or indexed for tuple structs) field of struct/enum variant
+    Field(String),
+    /// Enum variant
+    Variant(String),
+    /// Element of Vec (or other list-like collection)
+    Index(usize),
+    /// Value of HashMap (or other map-like collection)
+    Key(String),
+}
+
+/// Path to (nested) field, eg. `network.proxy::Http.ports[2]`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FieldPath(pub Vec<FieldPathSegment>);
+
+impl FieldPath {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Path with `segment` added at the end
+    pub fn with(mut self, segment: FieldPathSegment) -> Self {
+        self.0.push(segment);
+        self
+    }
+    /// Path with `segment` added at the beginning
+    pub fn prepend(mut self, segment: FieldPathSegment) -> Self {
+        self.0.insert(0, segment);
+        self
+    }
+}
+
+impl std::fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, segment) in self.0.iter().enumerate() {
+            match segment {
+                FieldPathSegment::Field(name) if idx == 0 => write!(f, "{}", name)?,
+                FieldPathSegment::Field(name) => write!(f, ".{}", name)?,
+                FieldPathSegment::Variant(name) => write!(f, "::{}", name)?,
+                FieldPathSegment::Index(idx) => write!(f, "[{}]", idx)?,
+                FieldPathSegment::Key(key) => write!(f, "[{:?}]", key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+///Mark field shown with `ctx` as changed (if tracked by [`show_top_tracked`](EguiStruct::show_top_tracked))
+fn track_change(ctx: &ViewContext) {
+    if let Some(changed) = &ctx.state.changed {
+        let path = ctx.path();
+        let mut changed = changed.borrow_mut();
+        if !changed.contains(&path) {
+            changed.push(path)
+        }
+    }
+}
+
+/// Undo/redo history of value shown with [`.show_top_with_history()`](EguiStruct::show_top_with_history)
+///
+/// Stores snapshots (clones) of value after each change; on undo/redo value is restored with [`EguiStructClone`]
+pub struct History<T> {
+    undo: Vec<T>,
+    redo: Vec<T>,
+    ///Value after last commited change
+    current: Option<T>,
+    ///Value has changed since last commit
+    pending: bool,
+    /// Maximum number of stored undo steps
+    pub max_len: usize,
+}
+
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl<T> History<T> {
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            current: None,
+            pending: false,
+            max_len,
+        }
+    }
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty() || self.pending
+    }
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+    /// Remove all undo/redo steps
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.current = None;
+        self.pending = false;
+    }
+}
+
+impl<T: EguiStructClone + EguiStructEq + Clone> History<T> {
+    fn begin(&mut self, value: &T) {
+        //value changed outside of view (eg. by program) is new starting point of next change
+        if !self.pending && !self.current.as_ref().is_some_and(|c| c.eguis_eq(value)) {
+            self.current = Some(value.clone());
+        }
+    }
+    /// Store changes made to `value` as single undo step
+    pub fn commit(&mut self, value: &T) {
+        if let Some(previous) = self.current.replace(value.clone()) {
+            self.undo.push(previous);
+            if self.undo.len() > self.max_len {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.pending = false;
+    }
+    fn update(&mut self, value: &T, changed: bool, dragging: bool) {
+        self.pending |= changed;
+        if self.pending && !dragging {
+            self.commit(value);
+        }
+    }
+    /// Revert `value` to state before last change; returns false if there is nothing to undo
+    pub fn undo(&mut self, value: &mut T) -> bool {
+        if self.pending {
+            self.commit(value);
+        }
+        let Some(previous) = self.undo.pop() else {
+            return false;
+        };
+        self.redo.push(value.clone());
+        value.eguis_clone(&previous);
+        self.current = Some(previous);
+        true
+    }
+    /// Reapply last undone change; returns false if there is nothing to redo
+    pub fn redo(&mut self, value: &mut T) -> bool {
+        let Some(next) = self.redo.pop() else {
+            return false;
+        };
+        self.undo.push(value.clone());
+        value.eguis_clone(&next);
+        self.current = Some(next);
+        true
+    }
+}
+
+/// Button clicked in [EguiStructEditor]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorAction {
+    None,
+    /// Staged changes were written to value
+    Apply,
+    /// Staged changes were discarded
+    Cancel,
 }
 
-/// Trait, that allows generating mutable view of data (takes `&mut data`)
+/// Staged editor: changes are made on working copy & written to value only after `Apply` button is clicked
 ///
-///  For end user (if you implement trait with macro & not manualy) ofers one function [`.show_top()`](Self::show_top), which displays struct inside scroll area.
-pub trait EguiStruct: EguiStructClone + EguiStructEq {
-    generate_show! { show_top, show_collapsing, show_collapsing_inner, show_primitive, show_childs, start_collapsed,
-    &mut Self, ConfigType, COLUMN_COUNT, SIMPLE, has_childs, has_primitive }
+/// Fields that differ from value have reset button (that reverts field to value)
+pub struct EguiStructEditor<T> {
+    staged: Option<T>,
 }
-/// Trait, that allows generating immutable view of data (takes `&data`)
-pub trait EguiStructImut {
-    generate_show! { show_top_imut, show_collapsing_imut, show_collapsing_inner_imut, show_primitive_imut, show_childs_imut, start_collapsed_imut,
-    &Self, ConfigTypeImut, COLUMN_COUNT_IMUT, SIMPLE_IMUT, has_childs_imut, has_primitive_imut }
+
+impl<T> Default for EguiStructEditor<T> {
+    fn default() -> Self {
+        Self { staged: None }
+    }
+}
+
+impl<T: EguiStruct + Clone + 'static> EguiStructEditor<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Working copy (if editor was shown since last cancel)
+    pub fn staged(&self) -> Option<&T> {
+        self.staged.as_ref()
+    }
+    /// Working copy differs from `value`
+    pub fn is_dirty(&self, value: &T) -> bool {
+        self.staged.as_ref().is_some_and(|s| !s.eguis_eq(value))
+    }
+    /// Write working copy to `value`
+    pub fn apply(&mut self, value: &mut T) {
+        if let Some(staged) = &self.staged {
+            value.eguis_clone(staged);
+        }
+    }
+    /// Discard working copy (it will be copied from value again on next show)
+    pub fn cancel(&mut self) {
+        self.staged = None;
+    }
+    /// Show working copy of `value` (like [`.show_top()`](EguiStruct::show_top)) with Apply/Cancel/Reset all buttons below it
+    ///
+    /// `Reset all` button (shown if `reset2` is `Some`) resets working copy to `reset2`
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        label: impl Into<WidgetText> + Clone,
+        value: &mut T,
+        reset2: Option<&T>,
+    ) -> (Response, EditorAction) {
+        let staged = self.staged.get_or_insert_with(|| value.clone());
+        let mut response = ui
+            .vertical(|ui| {
+                let max_height = ui.available_height() - ui.spacing().interact_size.y * 2.0;
+                ui.set_max_height(max_height.max(0.0));
+                staged.show_top(ui, label, Some(value))
+            })
+            .inner;
+        let dirty = !staged.eguis_eq(value);
+
+        let mut action = EditorAction::None;
+        ui.separator();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    dirty,
+                    Button::new(translate(ui, "egui_struct.apply", "Apply")),
+                )
+                .clicked()
+            {
+                action = EditorAction::Apply;
+            }
+            if ui
+                .button(translate(ui, "egui_struct.cancel", "Cancel"))
+                .clicked()
+            {
+                action = EditorAction::Cancel;
+            }
+            if let Some(reset2) = reset2 {
+                let enabled = !staged.eguis_eq(reset2);
+                if ui
+                    .add_enabled(
+                        enabled,
+                        Button::new(translate(ui, "egui_struct.reset_all", "Reset all")),
+                    )
+                    .clicked()
+                {
+                    staged.eguis_clone(reset2);
+                    response.mark_changed();
+                }
+            }
+            if dirty {
+                ui.label("●");
+            }
+        });
+        match action {
+            EditorAction::Apply => self.apply(value),
+            EditorAction::Cancel => self.cancel(),
+            EditorAction::None => (),
+        }
+        (response, action)
+    }
+}
+
+#[doc(hidden)]
+///Add errors of nested data to `errors` (prefixed with `segment`)
+pub fn validate_nested(
+    errors: &mut Vec<(FieldPath, String)>,
+    segment: FieldPathSegment,
+    nested: Vec<(FieldPath, String)>,
+) {
+    errors.extend(
+        nested
+            .into_iter()
+            .map(|(path, e)| (path.prepend(segment.clone()), e)),
+    );
 }
 
 /// Config structure for mutable view of Numerics
@@ -320,12 +1940,24 @@ macro_rules! impl_num_primitives {
                         Self::ConfigType::ComboBox(iter) => show_combobox(self, ui, Some(iter), id),
                     }
                 }
+                fn value_text(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+                fn paste_text(&mut self, text: &str) -> bool {
+                    text.trim().parse().map(|v| *self = v).is_ok()
+                }
+                fn default_value(&self) -> Option<Box<Self>> {
+                    Some(Box::default())
+                }
             }
             impl EguiStructImut for $typ {
                 type ConfigTypeImut<'a> = ConfigStrImut;
                 fn show_primitive_imut(&self, ui: &mut Ui, config: Self::ConfigTypeImut<'_>, _id: impl Hash  + Clone) -> Response {
                     self.to_string().as_str().show_primitive_imut(ui, config, ())
                 }
+                fn value_text_imut(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
             }
             impl_eeqclone!{$typ}
         )*
@@ -344,6 +1976,15 @@ impl EguiStruct for bool {
     ) -> Response {
         egui::Checkbox::without_text(self).ui(ui)
     }
+    fn copy_text(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+    fn paste_text(&mut self, text: &str) -> bool {
+        text.trim().parse().map(|v| *self = v).is_ok()
+    }
+    fn default_value(&self) -> Option<Box<Self>> {
+        Some(Box::default())
+    }
 }
 impl EguiStructImut for bool {
     type ConfigTypeImut<'a> = ();
@@ -355,6 +1996,9 @@ impl EguiStructImut for bool {
     ) -> Response {
         ui.add_enabled(false, egui::Checkbox::without_text(&mut self.clone()))
     }
+    fn copy_text_imut(&self) -> Option<String> {
+        Some(self.to_string())
+    }
 }
 impl_eeqclone! {bool}
 /////////////////////////////////////////////////////////
@@ -370,6 +2014,86 @@ pub enum ConfigStr<'a> {
 
     ///Combobox with available options specified by included iterator
     ComboBox(&'a mut dyn Iterator<Item = String>),
+
+    ///single line `egui::TextEdit` with dropdown of suggestions (specified by included iterator) filtered by entered text
+    ///
+    /// Unlike [ConfigStr::ComboBox] accepts also values that are not on the list
+    Autocomplete(&'a mut dyn Iterator<Item = String>),
+
+    ///single line `egui::TextEdit` with masked content (with button to reveal it)
+    Password,
+
+    ///multi line `egui::TextEdit` with monospace font (tab inserts `\t` instead of moving focus)
+    Code,
+
+    ///`egui::TextEdit` customised with [ConfigStrEdit]
+    TextEdit(ConfigStrEdit<'a>),
+}
+
+///Kind of `egui::TextEdit` used by [ConfigStrEdit]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StrEditMode {
+    ///Default: single line `egui::TextEdit`
+    #[default]
+    SingleLine,
+
+    ///multi line `egui::TextEdit`
+    MultiLine,
+
+    ///single line `egui::TextEdit` with masked content (with button to reveal it)
+    Password,
+
+    ///multi line `egui::TextEdit` with monospace font (tab inserts `\t` instead of moving focus)
+    Code,
+}
+
+///Detailed config of `egui::TextEdit` for mutable view of String (see [ConfigStr::TextEdit])
+///
+/// ```ignore
+/// #[eguis(config = ConfigStr::TextEdit(ConfigStrEdit::new(StrEditMode::SingleLine).hint_text("hostname").char_limit(64)))]
+/// host: String,
+/// ```
+#[derive(Default)]
+pub struct ConfigStrEdit<'a> {
+    pub mode: StrEditMode,
+    ///Text shown when string is empty
+    pub hint_text: Option<WidgetText>,
+    ///Maximum number of characters
+    pub char_limit: Option<usize>,
+    pub desired_width: Option<f32>,
+    ///Number of rows shown (only for multi line modes)
+    pub desired_rows: Option<usize>,
+    ///Predicate called on each frame, `Err(message)` marks text as invalid (and message is shown on hover)
+    #[allow(clippy::type_complexity)]
+    pub validator: Option<Box<dyn Fn(&str) -> Result<(), String> + 'a>>,
+}
+impl<'a> ConfigStrEdit<'a> {
+    pub fn new(mode: StrEditMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+    pub fn hint_text(mut self, hint_text: impl Into<WidgetText>) -> Self {
+        self.hint_text = Some(hint_text.into());
+        self
+    }
+    pub fn char_limit(mut self, char_limit: usize) -> Self {
+        self.char_limit = Some(char_limit);
+        self
+    }
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+    pub fn desired_rows(mut self, desired_rows: usize) -> Self {
+        self.desired_rows = Some(desired_rows);
+        self
+    }
+    pub fn validator(mut self, validator: impl Fn(&str) -> Result<(), String> + 'a) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
 }
 
 ///Config structure for immutable view of many simple types like str, String & numerics
@@ -395,8 +2119,77 @@ impl EguiStruct for String {
             ConfigStr::SingleLine => ui.text_edit_singleline(self),
             ConfigStr::MultiLine => ui.text_edit_multiline(self),
             ConfigStr::ComboBox(iter) => show_combobox(self, ui, Some(iter), id),
+            ConfigStr::Autocomplete(iter) => show_autocomplete(self, ui, iter, id),
+            ConfigStr::Password => {
+                show_text_edit(self, ui, ConfigStrEdit::new(StrEditMode::Password), id)
+            }
+            ConfigStr::Code => show_text_edit(self, ui, ConfigStrEdit::new(StrEditMode::Code), id),
+            ConfigStr::TextEdit(config) => show_text_edit(self, ui, config, id),
         }
     }
+    fn value_text(&self) -> Option<String> {
+        Some(self.clone())
+    }
+    fn paste_text(&mut self, text: &str) -> bool {
+        text.clone_into(self);
+        true
+    }
+    fn default_value(&self) -> Option<Box<Self>> {
+        Some(Box::default())
+    }
+}
+
+fn show_text_edit(
+    text: &mut String,
+    ui: &mut Ui,
+    config: ConfigStrEdit<'_>,
+    id: impl Hash + Clone,
+) -> Response {
+    let error = config.validator.as_ref().and_then(|f| f(text).err());
+    ui.horizontal(|ui| {
+        let mut reveal = false;
+        let reveal_id = ui.make_persistent_id((id, "__EguiStruct_password_reveal"));
+        if config.mode == StrEditMode::Password {
+            reveal = ui.data_mut(|d| d.get_temp(reveal_id).unwrap_or(false));
+        }
+        let mut edit = match config.mode {
+            StrEditMode::SingleLine => egui::TextEdit::singleline(text),
+            StrEditMode::MultiLine => egui::TextEdit::multiline(text),
+            StrEditMode::Password => egui::TextEdit::singleline(text).password(!reveal),
+            StrEditMode::Code => egui::TextEdit::multiline(text).code_editor(),
+        };
+        if let Some(hint_text) = config.hint_text {
+            edit = edit.hint_text(hint_text);
+        }
+        #[cfg(not(feature = "egui21"))]
+        if let Some(char_limit) = config.char_limit {
+            edit = edit.char_limit(char_limit);
+        }
+        if let Some(desired_width) = config.desired_width {
+            edit = edit.desired_width(desired_width);
+        }
+        if let Some(desired_rows) = config.desired_rows {
+            edit = edit.desired_rows(desired_rows);
+        }
+        if error.is_some() {
+            edit = edit.text_color(ui.visuals().error_fg_color);
+        }
+        let mut ret = edit.ui(ui);
+        #[cfg(feature = "egui21")]
+        if let Some((idx, _)) = config.char_limit.and_then(|l| text.char_indices().nth(l)) {
+            text.truncate(idx);
+        }
+        if config.mode == StrEditMode::Password && ui.toggle_value(&mut reveal, "👁").clicked() {
+            ui.data_mut(|d| d.insert_temp(reveal_id, reveal));
+        }
+        if let Some(error) = error {
+            ret = ret.on_hover_text(error.clone());
+            ui.colored_label(ui.visuals().error_fg_color, "⚠")
+                .on_hover_text(error);
+        }
+        ret
+    })
+    .inner
 }
 impl EguiStructImut for String {
     type ConfigTypeImut<'a> = ConfigStrImut;
@@ -408,6 +2201,9 @@ impl EguiStructImut for String {
     ) -> Response {
         self.as_str().show_primitive_imut(ui, config, ())
     }
+    fn value_text_imut(&self) -> Option<String> {
+        Some(self.clone())
+    }
 }
 impl_eeqclone! {String}
 
@@ -424,6 +2220,9 @@ impl EguiStructImut for str {
             ConfigStrImut::Selectable => ui.text_edit_singleline(&mut self),
         }
     }
+    fn value_text_imut(&self) -> Option<String> {
+        Some(self.to_string())
+    }
 }
 
 /////////////////////////////////////////////////////////
@@ -436,6 +2235,9 @@ impl<T: EguiStructImut + Default> EguiStructImut for Option<T> {
     fn has_primitive_imut(&self) -> bool {
         true
     }
+    fn value_text_imut(&self) -> Option<String> {
+        self.as_ref().and_then(|x| x.value_text_imut())
+    }
     fn show_primitive_imut(
         &self,
         ui: &mut Ui,
@@ -456,24 +2258,30 @@ impl<T: EguiStructImut + Default> EguiStructImut for Option<T> {
     fn show_childs_imut(
         &self,
         ui: &mut Ui,
+        ctx: &ViewContext,
         indent_level: isize,
         mut response: Response,
         _reset2: Option<&Self>,
+        left: Option<&Self>,
         id: Id,
     ) -> Response {
         if let Some(inner) = self {
+            let left = left.unwrap_or(&None).as_ref();
             if inner.has_primitive_imut() {
                 response |= inner.show_collapsing_imut(
                     ui,
+                    ctx,
                     "[0]",
                     "",
                     indent_level,
                     Default::default(),
                     None,
+                    left,
                     id,
                 );
             } else {
-                response |= inner.show_childs_imut(ui, indent_level, response.clone(), None, id)
+                response |=
+                    inner.show_childs_imut(ui, ctx, indent_level, response.clone(), None, left, id)
             }
         }
         response
@@ -488,6 +2296,23 @@ impl<T: EguiStruct + Default> EguiStruct for Option<T> {
     fn has_primitive(&self) -> bool {
         true
     }
+    fn value_text(&self) -> Option<String> {
+        self.as_ref().and_then(|x| x.value_text())
+    }
+    fn paste_text(&mut self, text: &str) -> bool {
+        if let Some(value) = self {
+            return value.paste_text(text);
+        }
+        let mut value = T::default();
+        let pasted = value.paste_text(text);
+        if pasted {
+            *self = Some(value);
+        }
+        pasted
+    }
+    fn default_value(&self) -> Option<Box<Self>> {
+        Some(Box::new(None))
+    }
     fn show_primitive(
         &mut self,
         ui: &mut Ui,
@@ -513,34 +2338,47 @@ impl<T: EguiStruct + Default> EguiStruct for Option<T> {
     fn show_childs(
         &mut self,
         ui: &mut Ui,
+        ctx: &ViewContext,
         indent_level: isize,
         mut response: Response,
         reset2: Option<&Self>,
+        left: Option<&Self>,
         id: Id,
     ) -> Response {
         if let Some(inner) = self {
+            let left = left.unwrap_or(&None).as_ref();
             if inner.has_primitive() {
                 response |= inner.show_collapsing(
                     ui,
+                    ctx,
                     "[0]",
                     "",
                     indent_level,
                     Default::default(),
                     reset2.unwrap_or(&None).as_ref(),
+                    left,
                     id,
                 );
             } else {
                 response |= inner.show_childs(
                     ui,
+                    ctx,
                     indent_level,
                     response.clone(),
                     reset2.unwrap_or(&None).as_ref(),
+                    left,
                     id,
                 )
             }
         }
         response
     }
+    fn validate_all(&self) -> Vec<(FieldPath, String)> {
+        self.as_ref().map_or(Vec::new(), |x| x.validate_all())
+    }
+    fn validate_self(&self) -> Result<(), String> {
+        self.as_ref().map_or(Ok(()), |x| x.validate_self())
+    }
 }
 impl<T: EguiStructClone + Default> EguiStructClone for Option<T> {
     fn eguis_clone(&mut self, source: &Self) {
@@ -573,7 +2411,7 @@ impl<T: EguiStructEq> EguiStructEq for Option<T> {
 ///////////////////////////////////////////////////
 macro_rules! impl_vec {
     ($Self:ty, $typ:ty, $iter:ident, $collapsing_name:ident, $childs_name:ident, $start_collapsed:ident,
-        $trait:ident, $SIMPLE:ident, $ConfigType:ident, $has_childs_imut:ident, $has_primitive:ident) => {
+        $trait:ident, $SIMPLE:ident, $ConfigType:ident, $has_childs_imut:ident, $has_primitive:ident, |$reset2:ident, $left:ident, $idx:ident| ($elem_reset2:expr, $elem_left:expr), {$($extra:tt)*}) => {
 
         impl<T: $trait> $trait for $typ{
             const $SIMPLE: bool = false;
@@ -587,28 +2425,40 @@ macro_rules! impl_vec {
             fn $childs_name(
                 self: $Self,
                 ui: &mut Ui,
+                ctx: &ViewContext,
                 indent_level: isize,
                 mut response: Response,
-                _reset2: Option<&Self>,
+                $reset2: Option<&Self>,
+                $left: Option<&Self>,
                 id: Id
             ) -> Response {
-                self.$iter().enumerate().for_each(|(idx, x)| {
-                    response |= x.$collapsing_name(ui, idx.to_string(), "", indent_level, Default::default(), None, id)
+                self.$iter().enumerate().for_each(|($idx, x)| {
+                    let ctx = &ctx.with_index($idx);
+                    response |= x.$collapsing_name(ui, ctx, $idx.to_string(), "", indent_level, Default::default(), $elem_reset2, $elem_left, id);
                 });
                 response
             }
             fn $start_collapsed(&self) -> bool {
                 self.len() > 16
             }
+            $($extra)*
         }
     };
     (IMUT, $($typ:ty)*) => { $(impl_vec! {&Self, $typ, iter, show_collapsing_imut, show_childs_imut, start_collapsed_imut,
-        EguiStructImut, SIMPLE_IMUT, ConfigTypeImut, has_childs_imut, has_primitive_imut})* };
+        EguiStructImut, SIMPLE_IMUT, ConfigTypeImut, has_childs_imut, has_primitive_imut, |_reset2, _left, idx| (None, None), {}})* };
     ($($typ:ty)*) => {
         $(
             impl_vec! {IMUT, $typ}
             impl_vec! {&mut Self, $typ, iter_mut, show_collapsing, show_childs, start_collapsed,
-                EguiStruct, SIMPLE, ConfigType, has_childs, has_primitive}
+                EguiStruct, SIMPLE, ConfigType, has_childs, has_primitive, |_reset2, left, idx| (None, left.and_then(|l| l.get(idx))), {
+                fn validate_all(&self) -> Vec<(FieldPath, String)> {
+                    let mut errors = Vec::new();
+                    self.iter().enumerate().for_each(|(idx, x)| {
+                        validate_nested(&mut errors, FieldPathSegment::Index(idx), x.validate_all())
+                    });
+                    errors
+                }
+            }}
 
             impl<T: EguiStructClone> EguiStructClone for $typ {
                 fn eguis_clone(&mut self, source: &Self) {
@@ -635,7 +2485,7 @@ impl_vec! {IMUT, indexmap::IndexSet<T> }
 /////////////////////////////////////////////////
 macro_rules! impl_map {
     ($Self:ty, $typ:ty, [$( $Qbound:path),*], $iter:ident, $collapsing_name:ident, $childs_name:ident, $start_collapsed:ident,
-        $trait:ident, $SIMPLE:ident, $ConfigType:ident, $has_childs_imut:ident, $has_primitive:ident) => {
+        $trait:ident, $SIMPLE:ident, $ConfigType:ident, $has_childs_imut:ident, $has_primitive:ident, |$left:ident, $q:ident| $elem_left:expr, {$($extra:tt)*}) => {
 
         impl<Q: ToString $(+ $Qbound)*, V: $trait> $trait for $typ{
             const $SIMPLE: bool = false;
@@ -649,33 +2499,47 @@ macro_rules! impl_map {
             fn $childs_name(
                 self: $Self,
                 ui: &mut Ui,
+                ctx: &ViewContext,
                 indent_level: isize,
                 mut response: Response,
-                _reset2: Option<&Self>,id:Id
+                _reset2: Option<&Self>,
+                $left: Option<&Self>,
+                id: Id
             ) -> Response {
-                self.$iter().for_each(|(q, v)| {
+                self.$iter().for_each(|($q, v)| {
                     response |= v.$collapsing_name(
                         ui,
-                        q.to_string(),
+                        &ctx.with_key($q),
+                        $q.to_string(),
                         "",
                         indent_level,
                         Default::default(),
                         None,
+                        $elem_left,
                         id
-                    )
+                    );
                 });
                 response
             }
             fn $start_collapsed(&self) -> bool {
                 self.len() > 16
             }
+            $($extra)*
         }
     };
     ($typ:ty) => {
         impl_map! {&Self, $typ, [], iter, show_collapsing_imut, show_childs_imut, start_collapsed_imut,
-            EguiStructImut, SIMPLE_IMUT, ConfigTypeImut, has_childs_imut, has_primitive_imut}
+            EguiStructImut, SIMPLE_IMUT, ConfigTypeImut, has_childs_imut, has_primitive_imut, |_left, q| None, {}}
         impl_map! {&mut Self, $typ, [Eq, std::hash::Hash], iter_mut, show_collapsing, show_childs, start_collapsed,
-            EguiStruct, SIMPLE, ConfigType, has_childs, has_primitive}
+            EguiStruct, SIMPLE, ConfigType, has_childs, has_primitive, |left, q| left.and_then(|l| l.get(q)), {
+            fn validate_all(&self) -> Vec<(FieldPath, String)> {
+                let mut errors = Vec::new();
+                self.iter().for_each(|(q, v)| {
+                    validate_nested(&mut errors, FieldPathSegment::Key(q.to_string()), v.validate_all())
+                });
+                errors
+            }
+        }}
 
         impl<Q: ToString + Eq + std::hash::Hash, V: EguiStructClone> EguiStructClone for $typ {
             fn eguis_clone(&mut self, source: &Self) {
@@ -714,6 +2578,9 @@ macro_rules! impl_large_numerics {
             fn show_primitive_imut(&self, ui: &mut Ui, _config: Self::ConfigTypeImut<'_>, _id: impl Hash + Clone) -> Response {
                 ui.label(self.to_string())
             }
+            fn value_text_imut(&self) -> Option<String> {
+                Some(self.to_string())
+            }
         }
         impl EguiStruct for $t {
             type ConfigType<'a> = ();
@@ -725,6 +2592,15 @@ macro_rules! impl_large_numerics {
                 }
                 ret
             }
+            fn value_text(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+            fn paste_text(&mut self, text: &str) -> bool {
+                text.trim().parse().map(|v| *self = v).is_ok()
+            }
+            fn default_value(&self) -> Option<Box<Self>> {
+                Some(Box::default())
+            }
         }
         impl_eeqclone!{$t}
     )*)
@@ -746,6 +2622,9 @@ impl<T: ToString> EguiStructImut for Combobox<T> {
     ) -> Response {
         self.0.to_string().show_primitive_imut(ui, config, ())
     }
+    fn value_text_imut(&self) -> Option<String> {
+        Some(self.0.to_string())
+    }
 }
 
 impl<T: Clone> EguiStructClone for Combobox<T> {
@@ -761,6 +2640,10 @@ impl<T: PartialEq> EguiStructEq for Combobox<T> {
 impl<T: Clone + ToString + PartialEq + 'static> EguiStruct for Combobox<T> {
     type ConfigType<'a> = Option<&'a mut dyn Iterator<Item = T>>;
 
+    fn value_text(&self) -> Option<String> {
+        Some(self.0.to_string())
+    }
+
     fn show_primitive(
         self: &mut Self,
         ui: &mut Ui,
@@ -771,30 +2654,109 @@ impl<T: Clone + ToString + PartialEq + 'static> EguiStruct for Combobox<T> {
     }
 }
 
+/// Combobox list longer than this will have search field (and only matching options will be shown)
+const COMBOBOX_SEARCH_THRESHOLD: usize = 16;
+
 fn show_combobox<'a, T: Clone + ToString + PartialEq>(
     sel: &mut T,
     ui: &mut Ui,
     config: Option<&'a mut dyn Iterator<Item = T>>,
     id: impl Hash + Clone,
+) -> Response {
+    let selected_text = sel.to_string();
+    show_combobox_popup(ui, selected_text, config, id, true, |ui, s, i| {
+        ui.selectable_value(sel, i, s)
+    })
+}
+
+/// Show combobox with options from `config` (each shown with `show_option(ui, option.to_string(), option)`)
+///
+/// - if `close_on_select` is false, popup will stay open after clicking option
+fn show_combobox_popup<T: ToString>(
+    ui: &mut Ui,
+    selected_text: String,
+    config: Option<&mut dyn Iterator<Item = T>>,
+    id: impl Hash + Clone,
+    close_on_select: bool,
+    mut show_option: impl FnMut(&mut Ui, String, T) -> Response,
 ) -> Response {
     let defspacing = ui.spacing().item_spacing.clone();
+    let search_id = ui.make_persistent_id((id.clone(), "__EguiStruct_combobox_search"));
+    //same as id used internally by egui::ComboBox
+    let popup_id = ui
+        .make_persistent_id(Id::new((id.clone(), "__EguiStruct_combobox")))
+        .with("popup");
+    let mut popup_rect = None;
+    let mut selected = false;
     ui.spacing_mut().item_spacing = egui::vec2(0.0, 0.0);
     let egui::InnerResponse { inner, response } =
         egui::ComboBox::from_id_source((id, "__EguiStruct_combobox"))
-            .selected_text(sel.to_string())
+            .selected_text(selected_text)
             .show_ui(ui, |ui| {
                 let mut inner_response =
                     ui.allocate_response(egui::vec2(0.0, 0.0), egui::Sense::hover());
                 ui.spacing_mut().item_spacing = defspacing;
-                if let Some(config) = config {
-                    for i in config {
-                        let s = i.to_string();
-                        inner_response |= ui.selectable_value(sel, i, s);
+                let mut options: Vec<(String, T)> = config
+                    .map(|config| config.map(|i| (i.to_string(), i)).collect())
+                    .unwrap_or_default();
+                if options.len() > COMBOBOX_SEARCH_THRESHOLD {
+                    let mut search: Option<String> =
+                        ui.data_mut(|d| d.get_temp(search_id)).flatten();
+                    let r = egui::TextEdit::singleline(search.get_or_insert_with(|| {
+                        ui.memory_mut(|m| m.request_focus(search_id));
+                        String::new()
+                    }))
+                    .id(search_id)
+                    .hint_text("🔍")
+                    .ui(ui);
+                    let query = search.as_deref().unwrap_or_default().to_lowercase();
+                    if !query.is_empty() {
+                        options.retain(|(s, _)| s.to_lowercase().contains(&query));
                     }
+                    ui.data_mut(|d| d.insert_temp(search_id, search));
+                    inner_response |= r;
                 }
+
+                let row_height = ui.spacing().interact_size.y.max(
+                    ui.text_style_height(&egui::TextStyle::Button)
+                        + 2.0 * ui.spacing().button_padding.y,
+                );
+                //combobox content is already inside ScrollArea with max_height = combo_height, so fit inside it
+                let max_height =
+                    (ui.spacing().combo_height - ui.min_rect().height() - defspacing.y)
+                        .max(row_height);
+                ScrollArea::vertical().max_height(max_height).show_rows(
+                    ui,
+                    row_height,
+                    options.len(),
+                    |ui, range| {
+                        for (s, i) in options.drain(range) {
+                            let r = show_option(ui, s, i);
+                            selected |= close_on_select && r.clicked();
+                            inner_response |= r;
+                        }
+                    },
+                );
+                popup_rect = Some(ui.clip_rect());
                 inner_response
             });
     ui.spacing_mut().item_spacing = defspacing;
+    if response.clicked() {
+        //combobox has been (re)opened- start with empty search field
+        ui.data_mut(|d| d.remove::<Option<String>>(search_id));
+    }
+    //egui::ComboBox closes on any click, keep it open if clicked inside (eg. on search field), but not on option
+    if let Some(popup_rect) = popup_rect {
+        let clicked_inside = ui.input(|i| {
+            i.pointer.any_click()
+                && i.pointer
+                    .interact_pos()
+                    .is_some_and(|pos| popup_rect.contains(pos))
+        });
+        if clicked_inside && !selected {
+            ui.memory_mut(|m| m.open_popup(popup_id));
+        }
+    }
     if let Some(mut iresp) = inner {
         iresp.layer_id = response.layer_id;
         response | iresp
@@ -802,6 +2764,118 @@ fn show_combobox<'a, T: Clone + ToString + PartialEq>(
         response
     }
 }
+
+/// Rank how well `candidate` matches `query` (lower is better; `None` if does not match)
+///
+/// Case-insensitive; prefix match is ranked first, then substring match & then fuzzy match (all query chars appear in order)
+fn autocomplete_rank(candidate: &str, query: &str) -> Option<u8> {
+    let candidate = candidate.to_lowercase();
+    let query = query.to_lowercase();
+    if candidate.starts_with(&query) {
+        Some(0)
+    } else if candidate.contains(&query) {
+        Some(1)
+    } else {
+        let mut chars = candidate.chars();
+        query.chars().all(|q| chars.any(|c| c == q)).then_some(2)
+    }
+}
+
+fn show_autocomplete(
+    text: &mut String,
+    ui: &mut Ui,
+    suggestions: &mut dyn Iterator<Item = String>,
+    id: impl Hash + Clone,
+) -> Response {
+    let popup_id = ui.make_persistent_id((id, "__EguiStruct_autocomplete"));
+    let highlight_id = popup_id.with("__EguiStruct_autocomplete_highlight");
+    let mut response = ui.text_edit_singleline(text);
+    if response.gained_focus() || response.changed() {
+        ui.memory_mut(|m| m.open_popup(popup_id));
+    }
+
+    let mut suggestions: Vec<(u8, String)> = suggestions
+        .filter_map(|s| autocomplete_rank(&s, text).map(|rank| (rank, s)))
+        .collect();
+    suggestions.sort_by_key(|(rank, _)| *rank);
+
+    let mut highlighted: Option<usize> = ui.data_mut(|d| d.get_temp(highlight_id)).flatten();
+    if response.changed() {
+        highlighted = None;
+    }
+    let popup_open = ui.memory(|m| m.is_popup_open(popup_id));
+    let mut scroll = false;
+    if popup_open && response.has_focus() && !suggestions.is_empty() {
+        let last = suggestions.len() - 1;
+        if ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown)) {
+            highlighted = Some(highlighted.map_or(0, |h| (h + 1).min(last)));
+            scroll = true;
+        }
+        if ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp)) {
+            highlighted = highlighted.and_then(|h| h.checked_sub(1));
+            scroll = true;
+        }
+    }
+    highlighted = highlighted.filter(|h| *h < suggestions.len());
+
+    if response.lost_focus() && !ui.input(|i| i.pointer.any_pressed()) {
+        //focus lost by keyboard (Enter/Tab)
+        if ui.input(|i| i.key_pressed(egui::Key::Enter)) && popup_open {
+            if let Some(h) = highlighted {
+                *text = suggestions[h].1.clone();
+                response.mark_changed();
+            }
+        }
+        ui.memory_mut(|m| m.close_popup());
+        highlighted = None;
+    }
+
+    let mut selected = None;
+    #[cfg(feature = "egui28")]
+    let popup = egui::popup_below_widget(
+        ui,
+        popup_id,
+        &response,
+        egui::PopupCloseBehavior::CloseOnClickOutside,
+        |ui| show_autocomplete_list(ui, &suggestions, highlighted, scroll, &mut selected),
+    );
+    #[cfg(not(feature = "egui28"))]
+    let popup = egui::popup_below_widget(ui, popup_id, &response, |ui| {
+        show_autocomplete_list(ui, &suggestions, highlighted, scroll, &mut selected)
+    });
+    if popup.is_none() {
+        highlighted = None;
+    }
+    if let Some(selected) = selected {
+        *text = suggestions[selected].1.clone();
+        response.mark_changed();
+        ui.memory_mut(|m| m.close_popup());
+        highlighted = None;
+    }
+    ui.data_mut(|d| d.insert_temp(highlight_id, highlighted));
+    response
+}
+
+fn show_autocomplete_list(
+    ui: &mut Ui,
+    suggestions: &[(u8, String)],
+    highlighted: Option<usize>,
+    scroll: bool,
+    selected: &mut Option<usize>,
+) {
+    ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+        for (idx, (_, suggestion)) in suggestions.iter().enumerate() {
+            let r = ui.selectable_label(highlighted == Some(idx), suggestion);
+            if scroll && highlighted == Some(idx) {
+                r.scroll_to_me(None);
+            }
+            if r.clicked() {
+                *selected = Some(idx);
+            }
+        }
+    });
+}
+
 impl<T> Deref for Combobox<T> {
     type Target = T;
 
@@ -841,3 +2915,157 @@ impl<T: PartialOrd> PartialOrd for Combobox<T> {
         self.0.partial_cmp(&other.0)
     }
 }
+
+////////////////////////////////////////////////////////////
+
+/// Wrapper for collection (eg. `Vec<T>`, `HashSet<T>`) that through `config: ConfigType` takes list of possible values
+/// and is shown as combobox with checkbox for each value
+pub struct MultiCombobox<C>(pub C);
+
+/// Collection that can be wrapped into [MultiCombobox]
+pub trait MultiComboboxCollection {
+    type Item: ToString;
+    fn contains_item(&self, item: &Self::Item) -> bool;
+    fn insert_item(&mut self, item: Self::Item);
+    fn remove_item(&mut self, item: &Self::Item);
+    fn items_len(&self) -> usize;
+    fn items_to_string(&self) -> Vec<String>;
+}
+
+impl<T: PartialEq + ToString> MultiComboboxCollection for Vec<T> {
+    type Item = T;
+    fn contains_item(&self, item: &T) -> bool {
+        self.contains(item)
+    }
+    fn insert_item(&mut self, item: T) {
+        if !self.contains(&item) {
+            self.push(item)
+        }
+    }
+    fn remove_item(&mut self, item: &T) {
+        self.retain(|x| x != item)
+    }
+    fn items_len(&self) -> usize {
+        self.len()
+    }
+    fn items_to_string(&self) -> Vec<String> {
+        self.iter().map(ToString::to_string).collect()
+    }
+}
+
+macro_rules! impl_multi_combobox_set {
+    ($typ:ty, $remove:ident) => {
+        impl<T: Eq + Hash + ToString> MultiComboboxCollection for $typ {
+            type Item = T;
+            fn contains_item(&self, item: &T) -> bool {
+                self.contains(item)
+            }
+            fn insert_item(&mut self, item: T) {
+                self.insert(item);
+            }
+            fn remove_item(&mut self, item: &T) {
+                self.$remove(item);
+            }
+            fn items_len(&self) -> usize {
+                self.len()
+            }
+            fn items_to_string(&self) -> Vec<String> {
+                self.iter().map(ToString::to_string).collect()
+            }
+        }
+    };
+}
+impl_multi_combobox_set! {std::collections::HashSet<T>, remove}
+#[cfg(feature = "indexmap")]
+impl_multi_combobox_set! {indexmap::IndexSet<T>, shift_remove}
+
+impl<C: MultiComboboxCollection> MultiCombobox<C> {
+    ///Selected items joined with ", "
+    fn selected_text(&self) -> String {
+        self.0.items_to_string().join(", ")
+    }
+}
+
+impl<C: MultiComboboxCollection> EguiStructImut for MultiCombobox<C> {
+    type ConfigTypeImut<'a> = ConfigStrImut;
+
+    fn show_primitive_imut(
+        &self,
+        ui: &mut Ui,
+        config: Self::ConfigTypeImut<'_>,
+        _id: impl Hash + Clone,
+    ) -> Response {
+        self.selected_text().show_primitive_imut(ui, config, ())
+    }
+    fn value_text_imut(&self) -> Option<String> {
+        Some(self.selected_text())
+    }
+}
+
+impl<C: Clone> EguiStructClone for MultiCombobox<C> {
+    fn eguis_clone(&mut self, source: &Self) {
+        self.0.clone_from(&source.0)
+    }
+}
+impl<C: PartialEq> EguiStructEq for MultiCombobox<C> {
+    fn eguis_eq(&self, rhs: &Self) -> bool {
+        self.0.eq(&rhs.0)
+    }
+}
+impl<C: MultiComboboxCollection + Clone + PartialEq + 'static> EguiStruct for MultiCombobox<C> {
+    type ConfigType<'a> = Option<&'a mut dyn Iterator<Item = C::Item>>;
+
+    fn value_text(&self) -> Option<String> {
+        Some(self.selected_text())
+    }
+
+    fn show_primitive(
+        &mut self,
+        ui: &mut Ui,
+        config: Self::ConfigType<'_>,
+        id: impl Hash + Clone,
+    ) -> Response {
+        let selected_text = translate(ui, "egui_struct.selected", "%{count} selected")
+            .replace("%{count}", &self.0.items_len().to_string());
+        show_combobox_popup(ui, selected_text, config, id, false, |ui, s, i| {
+            let mut checked = self.0.contains_item(&i);
+            let r = ui.checkbox(&mut checked, s);
+            if r.changed() {
+                if checked {
+                    self.0.insert_item(i)
+                } else {
+                    self.0.remove_item(&i)
+                }
+            }
+            r
+        })
+    }
+}
+impl<C> Deref for MultiCombobox<C> {
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<C> DerefMut for MultiCombobox<C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<C: Default> Default for MultiCombobox<C> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+impl<C: Clone> Clone for MultiCombobox<C> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+impl<C: Eq> Eq for MultiCombobox<C> {}
+impl<C: PartialEq> PartialEq for MultiCombobox<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
@@ -0,0 +1,494 @@
+extern crate egui28 as egui;
+extern crate self as rust_i18n;
+mod i18n;
+
+use egui_struct::*;
+
+#[derive(EguiStruct, Clone, Debug, PartialEq)]
+enum Mode {
+    Off,
+    On,
+    Level(u8),
+    Named {
+        value: u8,
+        label: String,
+    },
+    #[eguis(skip)]
+    Hidden,
+}
+
+#[derive(EguiStruct, Clone, Debug)]
+struct Mapped {
+    #[eguis(
+        map_pre_ref = u32::to_string,
+        map_post = (|field: &mut u32, mapped: String| { if let Ok(v) = mapped.parse() { *field = v; } })
+    )]
+    number: u32,
+}
+
+fn named(value: u8, label: &str) -> Mode {
+    Mode::Named {
+        value,
+        label: label.to_string(),
+    }
+}
+
+#[test]
+fn enum_eq_unit_variants() {
+    assert!(Mode::Off.eguis_eq(&Mode::Off));
+    assert!(!Mode::Off.eguis_eq(&Mode::On));
+    assert!(!Mode::On.eguis_eq(&Mode::Off));
+    assert!(!Mode::On.eguis_eq(&Mode::Level(0)));
+    assert!(!Mode::Level(0).eguis_eq(&Mode::On));
+    assert!(!Mode::Hidden.eguis_eq(&Mode::Off));
+    assert!(Mode::Hidden.eguis_eq(&Mode::Hidden));
+}
+
+#[test]
+fn enum_eq_variants_with_fields() {
+    assert!(Mode::Level(1).eguis_eq(&Mode::Level(1)));
+    assert!(!Mode::Level(1).eguis_eq(&Mode::Level(2)));
+    assert!(named(1, "a").eguis_eq(&named(1, "a")));
+    assert!(!named(1, "a").eguis_eq(&named(1, "b")));
+    assert!(!named(1, "a").eguis_eq(&Mode::Level(1)));
+}
+
+#[test]
+fn enum_clone() {
+    let sources = [Mode::Off, Mode::On, Mode::Level(7), named(3, "x")];
+    for source in &sources {
+        for target in &sources {
+            let mut value = target.clone();
+            value.eguis_clone(source);
+            assert_eq!(&value, source);
+            assert!(value.eguis_eq(source));
+        }
+    }
+}
+
+#[derive(EguiStruct, Clone, Debug, PartialEq)]
+enum Mismatched {
+    A(u8),
+    #[eguis(constructor = mismatched_b)]
+    B(u8),
+}
+
+/// Constructor of `Mismatched::B`, that creates another variant
+fn mismatched_b() -> Mismatched {
+    Mismatched::A(0)
+}
+
+#[test]
+fn enum_clone_mismatched_constructor() {
+    let mut value = Mismatched::A(1);
+    value.eguis_clone(&Mismatched::B(2));
+    assert_eq!(value, Mismatched::A(1));
+}
+
+#[test]
+fn map_pre_ref_eq() {
+    let a = Mapped { number: 1 };
+    assert!(a.eguis_eq(&Mapped { number: 1 }));
+    assert!(!a.eguis_eq(&Mapped { number: 2 }));
+}
+
+/// Run single frame with `add_contents` shown in central panel
+fn run_frame(add_contents: impl FnOnce(&mut egui::Ui)) {
+    run_frame_with(egui::RawInput::default(), add_contents)
+}
+
+/// Same as `run_frame`, but with given `input` (eg. key events)
+fn run_frame_with(input: egui::RawInput, add_contents: impl FnOnce(&mut egui::Ui)) {
+    let ctx = egui::Context::default();
+    let mut add_contents = Some(add_contents);
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            if let Some(add_contents) = add_contents.take() {
+                add_contents(ui)
+            }
+        });
+    });
+}
+
+#[test]
+fn editor_dirty_on_variant_change() {
+    let mut editor = EguiStructEditor::new();
+    let mut value = Mode::Off;
+    run_frame(|ui| {
+        editor.show(ui, "mode", &mut value, None);
+    });
+    assert!(!editor.is_dirty(&value));
+    assert!(editor.is_dirty(&Mode::On));
+    assert!(editor.is_dirty(&Mode::Level(0)));
+
+    let mut applied = Mode::On;
+    editor.apply(&mut applied);
+    assert_eq!(applied, Mode::Off);
+    assert!(!editor.is_dirty(&applied));
+}
+
+fn below_10(value: &u8) -> Result<(), String> {
+    if *value < 10 {
+        Ok(())
+    } else {
+        Err("too big".to_string())
+    }
+}
+
+fn not_empty(value: &Validated) -> Result<(), String> {
+    if value.items.is_empty() {
+        Err("empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(EguiStruct, Clone, Debug, Default)]
+#[eguis(validate = not_empty)]
+struct Validated {
+    #[eguis(validate = below_10)]
+    value: u8,
+    show_hidden: bool,
+    #[eguis(visible_if = self.show_hidden, validate = below_10)]
+    hidden: u8,
+    items: Vec<Item>,
+    choice: Choice,
+}
+
+#[derive(EguiStruct, Clone, Debug, Default)]
+struct Item {
+    #[eguis(validate = below_10)]
+    value: u8,
+}
+
+#[derive(EguiStruct, Clone, Debug, Default)]
+enum Choice {
+    #[default]
+    None,
+    Some {
+        #[eguis(validate = below_10)]
+        value: u8,
+    },
+}
+
+#[test]
+fn validate_all_paths() {
+    let mut data = Validated {
+        items: vec![Item { value: 1 }],
+        ..Default::default()
+    };
+    assert!(data.validate_all().is_empty());
+    assert!(data.validate_self().is_ok());
+
+    data.value = 10;
+    data.hidden = 10;
+    data.items = vec![Item { value: 1 }, Item { value: 20 }];
+    data.choice = Choice::Some { value: 30 };
+    let paths = |data: &Validated| {
+        data.validate_all()
+            .into_iter()
+            .map(|(path, e)| format!("{}: {}", path, e))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        paths(&data),
+        [
+            "value: too big",
+            "items[1].value: too big",
+            "choice::Some.value: too big"
+        ]
+    );
+
+    data.show_hidden = true;
+    data.items.clear();
+    assert_eq!(
+        paths(&data),
+        [
+            "value: too big",
+            "hidden: too big",
+            "choice::Some.value: too big",
+            ": empty"
+        ]
+    );
+    assert_eq!(data.validate_self(), Err("empty".to_string()));
+}
+
+#[test]
+fn history_undo_redo() {
+    let mut history = History::new(100);
+    let mut value = Mode::Off;
+    assert!(!history.can_undo());
+    history.commit(&value);
+    assert!(!history.can_undo());
+
+    value = Mode::On;
+    history.commit(&value);
+    //changes made before commit are stored as single step
+    value = Mode::Level(1);
+    value = match value {
+        Mode::Level(x) => Mode::Level(x + 1),
+        other => other,
+    };
+    history.commit(&value);
+    assert!(history.can_undo());
+    assert!(!history.can_redo());
+
+    assert!(history.undo(&mut value));
+    assert_eq!(value, Mode::On);
+    assert!(history.undo(&mut value));
+    assert_eq!(value, Mode::Off);
+    assert!(!history.undo(&mut value));
+    assert_eq!(value, Mode::Off);
+
+    assert!(history.redo(&mut value));
+    assert_eq!(value, Mode::On);
+    assert!(history.redo(&mut value));
+    assert_eq!(value, Mode::Level(2));
+    assert!(!history.redo(&mut value));
+
+    //new change discards redo steps
+    history.undo(&mut value);
+    value = named(1, "a");
+    history.commit(&value);
+    assert!(!history.can_redo());
+    assert!(history.undo(&mut value));
+    assert_eq!(value, Mode::On);
+}
+
+#[test]
+fn history_max_len() {
+    let mut history = History::new(2);
+    let mut value = Mode::Level(0);
+    history.commit(&value);
+    for x in 1..=4 {
+        value = Mode::Level(x);
+        history.commit(&value);
+    }
+    assert!(history.undo(&mut value));
+    assert!(history.undo(&mut value));
+    assert!(!history.undo(&mut value));
+    assert_eq!(value, Mode::Level(2));
+
+    history.clear();
+    assert!(!history.can_undo());
+    assert!(!history.can_redo());
+}
+
+#[test]
+fn history_external_change() {
+    let mut history = History::default();
+    let mut value = Mode::Off;
+    history.commit(&value);
+
+    //value changed by program (not in view) is kept by undo of next change
+    value = Mode::On;
+    run_frame(|ui| {
+        value.show_top_with_history(ui, "mode", None, &mut history);
+    });
+    value = Mode::Level(1);
+    history.commit(&value);
+    assert!(history.undo(&mut value));
+    assert_eq!(value, Mode::On);
+}
+
+#[test]
+fn history_undo_shortcut() {
+    let mut history = History::default();
+    let mut value = Mode::Off;
+    history.commit(&value);
+    value = Mode::On;
+    history.commit(&value);
+
+    let input = egui::RawInput {
+        modifiers: egui::Modifiers::COMMAND,
+        events: vec![egui::Event::Key {
+            key: egui::Key::Z,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::COMMAND,
+        }],
+        ..Default::default()
+    };
+    run_frame_with(input, |ui| {
+        let response = value.show_top_with_history(ui, "mode", None, &mut history);
+        assert!(response.changed());
+    });
+    assert_eq!(value, Mode::Off);
+    assert!(history.can_redo());
+}
+
+#[test]
+fn field_path_display() {
+    assert_eq!(FieldPath::new().to_string(), "");
+    let path = FieldPath::new()
+        .with(FieldPathSegment::Field("network".to_string()))
+        .with(FieldPathSegment::Field("proxy".to_string()))
+        .with(FieldPathSegment::Variant("Http".to_string()))
+        .with(FieldPathSegment::Field("ports".to_string()))
+        .with(FieldPathSegment::Index(2));
+    assert_eq!(path.to_string(), "network.proxy::Http.ports[2]");
+    let path = FieldPath::new()
+        .with(FieldPathSegment::Key("a \"b\"".to_string()))
+        .with(FieldPathSegment::Field("0".to_string()))
+        .prepend(FieldPathSegment::Field("map".to_string()));
+    assert_eq!(path.to_string(), r#"map["a \"b\""].0"#);
+}
+
+#[test]
+fn view_context_path() {
+    let options = ShowOptions::default();
+    let state = ViewState::default();
+    let ctx = ViewContext::new(&options, &state);
+    assert_eq!(ctx.path(), FieldPath::new());
+    let key = "k";
+    let ctx = ctx.with_field("map");
+    let ctx = ctx.with_key(&key);
+    let ctx = ctx.with_variant("B");
+    let ctx = ctx.with_field("list");
+    let ctx = ctx.with_index(1);
+    assert_eq!(ctx.path().to_string(), r#"map["k"]::B.list[1]"#);
+}
+
+#[test]
+fn multi_combobox_value_text() {
+    let multi = MultiCombobox(vec!["a", "c"]);
+    assert_eq!(multi.value_text().as_deref(), Some("a, c"));
+    assert_eq!(multi.copy_text().as_deref(), Some("a, c"));
+    assert_eq!(multi.value_text_imut().as_deref(), Some("a, c"));
+}
+
+#[cfg(feature = "serde")]
+#[derive(EguiStruct, Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[eguis(clipboard)]
+struct Clip {
+    number: u32,
+    text: String,
+    list: Vec<u8>,
+    #[eguis(skip)]
+    #[serde(skip)]
+    skipped: u8,
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_paste_round_trip() {
+    let source = Clip {
+        number: 7,
+        text: "a \"quoted\" text".to_string(),
+        list: vec![1, 2],
+        skipped: 1,
+    };
+    let text = source.copy_text().unwrap();
+    //length of `Vec` is not changed by `eguis_clone`
+    let mut target = Clip {
+        list: vec![0, 0],
+        skipped: 2,
+        ..Default::default()
+    };
+    assert!(target.paste_text(&format!(" {}\n", text)));
+    assert_eq!(target.number, 7);
+    assert_eq!(target.text, source.text);
+    assert_eq!(target.list, source.list);
+    assert_eq!(target.skipped, 2);
+
+    assert!(!target.paste_text("(number: \"x\")"));
+    assert_eq!(target.number, 7);
+}
+
+/// Texts of all shapes drawn by `add_contents` (in second frame, first one is used by grid to measure itself)
+#[cfg(feature = "serde")]
+fn shown_texts(mut add_contents: impl FnMut(&mut egui::Ui)) -> Vec<String> {
+    let ctx = egui::Context::default();
+    let mut run = || {
+        ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+        })
+    };
+    run();
+    run()
+        .shapes
+        .into_iter()
+        .filter_map(|shape| match shape.shape {
+            egui::Shape::Text(text) => Some(text.galley.job.text.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(feature = "serde")]
+macro_rules! serde_rename_all {
+    ($($rule:literal => $module:ident),*) => {$(
+        mod $module {
+            use egui_struct::EguiStruct;
+
+            #[derive(EguiStruct, serde::Serialize)]
+            #[eguis(serde)]
+            #[serde(rename_all = $rule)]
+            pub struct Config {
+                pub my_host: u8,
+                pub max_value: u8,
+                pub first: Choice,
+                pub second: Choice,
+            }
+
+            #[derive(EguiStruct, serde::Serialize)]
+            #[eguis(serde)]
+            #[serde(rename_all = $rule)]
+            pub enum Choice {
+                #[serde(rename_all = $rule)]
+                MyVariant { inner_field: u8 },
+                OtherVariant { other_field: u8 },
+            }
+
+            impl Default for Choice {
+                fn default() -> Self {
+                    Self::MyVariant { inner_field: 0 }
+                }
+            }
+        }
+    )*
+        #[test]
+        fn serde_rename_all_labels() {
+            $({
+                use $module::*;
+                let mut config = Config {
+                    my_host: 1,
+                    max_value: 2,
+                    first: Choice::MyVariant { inner_field: 3 },
+                    second: Choice::OtherVariant { other_field: 4 },
+                };
+                //names that are not valid identifiers are written as raw identifiers (`r#my-host`)
+                let serialized = ron::to_string(&config).unwrap().replace("r#", "");
+                let labels = shown_texts(|ui| {
+                    config.show_top(ui, "config", None);
+                });
+                let names = serialized
+                    .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+                    .filter(|name| name.starts_with(|c: char| c.is_alphabetic()));
+                for name in names {
+                    assert!(
+                        labels.iter().any(|label| label == name),
+                        "{}: `{}` (of {}) is not shown (labels: {:?})",
+                        $rule,
+                        name,
+                        serialized,
+                        labels
+                    );
+                }
+            })*
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+serde_rename_all! {
+    "lowercase" => lowercase,
+    "UPPERCASE" => uppercase,
+    "PascalCase" => pascal_case,
+    "camelCase" => camel_case,
+    "snake_case" => snake_case,
+    "SCREAMING_SNAKE_CASE" => screaming_snake_case,
+    "kebab-case" => kebab_case,
+    "SCREAMING-KEBAB-CASE" => screaming_kebab_case
+}
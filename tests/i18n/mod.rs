@@ -0,0 +1,16 @@
+//! Stand-in for `rust_i18n` crate (with `i18n` feature derive macro output calls `::rust_i18n::t!`)
+//!
+//! Test crate root has to contain `extern crate self as rust_i18n;`; returns default text (from `doc` attribute) or key
+
+#[macro_export]
+macro_rules! t {
+    (#[doc = $text:expr] $key:expr) => {
+        ::std::string::String::from($text)
+    };
+    (#[$meta:meta] $($rest:tt)+) => {
+        $crate::t!($($rest)+)
+    };
+    ($key:expr) => {
+        ::std::string::String::from($key)
+    };
+}
@@ -0,0 +1,9 @@
+use egui_struct::*;
+
+#[derive(EguiStruct)]
+#[eguis(bound = "T EguiStruct")]
+struct Data<T> {
+    field: T,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use egui_struct::*;
+
+#[derive(EguiStruct)]
+struct Wrapper(#[eguis(visible_if = self.0 > 0)] u8);
+
+#[derive(EguiStruct)]
+enum Choice {
+    Value(#[eguis(enabled_if = true)] u8),
+    Pair(#[eguis(enabled_if = true)] u8, u8),
+}
+
+fn main() {}
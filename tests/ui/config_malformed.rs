@@ -0,0 +1,15 @@
+use egui_struct::*;
+
+#[derive(EguiStruct)]
+struct Data {
+    #[eguis(config = "Slider(5,")]
+    field: u8,
+}
+
+#[derive(EguiStruct)]
+struct Data2 {
+    #[eguis(config = "Slider(5 11)")]
+    field: u8,
+}
+
+fn main() {}
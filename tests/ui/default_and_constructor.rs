@@ -0,0 +1,13 @@
+use egui_struct::*;
+
+fn new_value() -> Choice {
+    Choice::Value(3)
+}
+
+#[derive(EguiStruct)]
+enum Choice {
+    #[eguis(default = Choice::Value(1), constructor = new_value)]
+    Value(u8),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use egui_struct::*;
+
+#[derive(EguiStruct)]
+enum Choice {
+    Empty,
+    #[eguis(default = Self::Empty)]
+    Value(u8),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use egui_struct::*;
+
+#[derive(EguiStruct)]
+#[eguis(group(name = "Network"))]
+struct Settings {
+    #[eguis(group = "Network")]
+    host: String,
+    #[eguis(group = "Netwrok")]
+    port: u16,
+}
+
+fn main() {}
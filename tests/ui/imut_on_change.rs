@@ -0,0 +1,17 @@
+use egui_struct::*;
+
+fn changed(_field: &mut u8) {}
+
+#[derive(EguiStruct)]
+struct Data {
+    #[eguis(imut, on_change = changed)]
+    field: u8,
+}
+
+#[derive(EguiStruct)]
+enum Choice {
+    #[eguis(imut)]
+    Value(#[eguis(on_change = changed)] u8),
+}
+
+fn main() {}
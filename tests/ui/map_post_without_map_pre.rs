@@ -0,0 +1,9 @@
+use egui_struct::*;
+
+#[derive(EguiStruct)]
+struct Data {
+    #[eguis(map_post = (|field: &mut u8, mapped: String| *field = mapped.len() as u8))]
+    field: u8,
+}
+
+fn main() {}
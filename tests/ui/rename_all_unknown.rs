@@ -0,0 +1,9 @@
+use egui_struct::*;
+
+#[derive(EguiStruct)]
+#[eguis(rename_all = "Shouting")]
+struct Data {
+    field: u8,
+}
+
+fn main() {}
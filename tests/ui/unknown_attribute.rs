@@ -0,0 +1,9 @@
+use egui_struct::*;
+
+#[derive(EguiStruct)]
+struct Data {
+    #[eguis(hnit = "typo")]
+    field: u8,
+}
+
+fn main() {}
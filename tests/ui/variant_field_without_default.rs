@@ -0,0 +1,24 @@
+extern crate egui28 as egui;
+extern crate self as rust_i18n;
+#[path = "../i18n/mod.rs"]
+mod i18n;
+
+use egui_struct::*;
+
+#[derive(Clone, PartialEq)]
+struct Handle(u32);
+impl_eeqclone!(Handle);
+impl EguiStructImut for Handle {
+    type ConfigTypeImut<'a> = ();
+}
+impl EguiStruct for Handle {
+    type ConfigType<'a> = ();
+}
+
+#[derive(EguiStruct)]
+enum Choice {
+    Empty,
+    Value(Handle),
+}
+
+fn main() {}